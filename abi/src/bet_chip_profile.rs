@@ -1,4 +1,4 @@
-use async_graphql_derive::SimpleObject;
+use async_graphql_derive::{InputObject, SimpleObject};
 use linera_sdk::linera_base_types::Amount;
 use serde::{Deserialize, Serialize};
 
@@ -6,14 +6,153 @@ use serde::{Deserialize, Serialize};
 pub struct Chip {
     pub amount: Amount,
     pub text: String,
+    pub color: String,
     pub enable: bool,
 }
 
+/// Configured chip of a chip set, `label` falls back to the formatted amount.
+#[derive(Debug, Clone, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ChipSpecInput")]
+pub struct ChipSpec {
+    pub amount: Amount,
+    pub label: Option<String>,
+    pub color: String,
+}
+
+/// A number of chips of one denomination placed as part of a bet.
+#[derive(Debug, Clone, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ChipPlacementInput")]
+pub struct ChipPlacement {
+    pub amount: Amount,
+    pub count: u32,
+}
+
 #[derive(Debug, Clone, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize, SimpleObject)]
 pub struct BetData {
     pub min_bet: Amount,
     pub max_bet: Amount,
-    pub chipset: Option<[Chip; 5]>,
+    pub chipset: Option<Vec<Chip>>,
+}
+
+impl BetData {
+    /// Validate a chip stack against the enabled chips and return its total.
+    pub fn chip_stack_total(&self, chip_stack: &[ChipPlacement]) -> Result<Amount, String> {
+        let chipset = self.chipset.as_ref().ok_or("no chips available for this bet")?;
+        let mut total = Amount::ZERO;
+
+        for placement in chip_stack {
+            if !chipset.iter().any(|chip| chip.enable && chip.amount == placement.amount) {
+                return Err(format!("chip {} is not enabled", placement.amount));
+            }
+            let value = placement
+                .amount
+                .try_mul(placement.count as u128)
+                .map_err(|_| "chip stack value overflow".to_string())?;
+            total = total.try_add(value).map_err(|_| "chip stack value overflow".to_string())?;
+        }
+
+        Ok(total)
+    }
+
+    /// Rebuild a chip stack for an amount with the largest enabled chips first.
+    /// Fails when a remainder smaller than the smallest enabled chip is left over.
+    pub fn chip_stack_for(&self, amount: Amount) -> Result<Vec<ChipPlacement>, String> {
+        let mut enabled: Vec<Amount> = self
+            .chipset
+            .iter()
+            .flatten()
+            .filter(|chip| chip.enable && !chip.amount.is_zero())
+            .map(|chip| chip.amount)
+            .collect();
+        enabled.sort_by(|a, b| b.cmp(a));
+
+        let mut remaining = u128::from(amount);
+        let mut chip_stack = Vec::new();
+        for chip in enabled {
+            let chip_value = u128::from(chip);
+            let count = remaining / chip_value;
+            if count > 0 {
+                chip_stack.push(ChipPlacement {
+                    amount: chip,
+                    count: u32::try_from(count).unwrap_or(u32::MAX),
+                });
+                remaining -= chip_value.saturating_mul(count);
+            }
+        }
+        if remaining > 0 {
+            return Err(format!("bet of {} can't be made with the enabled chips", amount));
+        }
+        Ok(chip_stack)
+    }
+}
+
+/// Face value of a chip stack, whether or not its chips are still enabled.
+pub fn chip_stack_value(chip_stack: &[ChipPlacement]) -> Amount {
+    chip_stack.iter().fold(Amount::ZERO, |total, placement| {
+        total.saturating_add(placement.amount.saturating_mul(placement.count as u128))
+    })
+}
+
+pub fn double_chip_stack(chip_stack: &[ChipPlacement]) -> Vec<ChipPlacement> {
+    chip_stack
+        .iter()
+        .map(|placement| ChipPlacement {
+            amount: placement.amount,
+            count: placement.count.saturating_mul(2),
+        })
+        .collect()
+}
+
+/// Max bets of the table a buy-in escrows, the rest of the balance stays spendable.
+pub const BUY_IN_MAX_BETS: u128 = 20;
+
+/// Stake level of a table, shared by every player seated at it.
+#[derive(Debug, Clone, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "TableStakesInput")]
+pub struct TableStakes {
+    pub min_bet: Amount,
+    pub max_bet: Amount,
+    pub chip_base: Amount,               // smallest chip, the ladder is 1x/5x/25x/100x/250x of it
+    pub chip_set: Option<Vec<ChipSpec>>, // custom chips replacing the chip_base ladder
+}
+
+impl TableStakes {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.chip_base.is_zero() {
+            return Err("chip base must be greater than zero".to_string());
+        }
+        if self.min_bet > self.max_bet {
+            return Err(format!("min bet {} is greater than max bet {}", self.min_bet, self.max_bet));
+        }
+        if let Some(chip_set) = &self.chip_set {
+            if chip_set.is_empty() {
+                return Err("chip set must have at least one chip".to_string());
+            }
+            if chip_set.iter().any(|chip| chip.amount.is_zero()) {
+                return Err("chip amount must be greater than zero".to_string());
+            }
+        }
+        // The smallest chip has to fit between the table limits, or no bet can be built from chips
+        let smallest_chip = self.smallest_chip();
+        if smallest_chip > self.max_bet {
+            return Err(format!("smallest chip {} is greater than max bet {}", smallest_chip, self.max_bet));
+        }
+        if self.min_bet < smallest_chip {
+            return Err(format!("min bet {} is below the smallest chip {}", self.min_bet, smallest_chip));
+        }
+        Ok(())
+    }
+
+    pub fn max_buy_in(&self) -> Amount {
+        self.max_bet.try_mul(BUY_IN_MAX_BETS).unwrap_or(Amount::MAX)
+    }
+
+    pub fn smallest_chip(&self) -> Amount {
+        self.chip_set
+            .as_ref()
+            .and_then(|chip_set| chip_set.iter().map(|chip| chip.amount).min())
+            .unwrap_or(self.chip_base)
+    }
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize, SimpleObject)]
@@ -21,6 +160,8 @@ pub struct Profile {
     pub seat: Option<u8>,
     pub balance: Amount,
     pub bet_data: Option<BetData>,
+    pub chip_stack: Vec<ChipPlacement>,      // chips of the current bet
+    pub last_chip_stack: Vec<ChipPlacement>, // chips of the last dealt bet
 }
 
 impl Profile {
@@ -61,100 +202,116 @@ impl Profile {
         }
 
         // Generate chip denominations
-        let denominations = [
-            base,                     // 1x
-            base.saturating_mul(5),   // 5x
-            base.saturating_mul(25),  // 25x
-            base.saturating_mul(100), // 100x
-            base.saturating_mul(250), // 250x
-        ];
-
-        // Generate chip list
-        let generated_chip_list = [
-            Chip {
-                amount: denominations[0],
-                text: format_chip_units(denominations[0].saturating_div(Amount::ONE)),
-                enable: denominations[0] <= self.balance,
-            },
-            Chip {
-                amount: denominations[1],
-                text: format_chip_units(denominations[1].saturating_div(Amount::ONE)),
-                enable: denominations[1] <= self.balance,
-            },
-            Chip {
-                amount: denominations[2],
-                text: format_chip_units(denominations[2].saturating_div(Amount::ONE)),
-                enable: denominations[2] <= self.balance,
-            },
-            Chip {
-                amount: denominations[3],
-                text: format_chip_units(denominations[3].saturating_div(Amount::ONE)),
-                enable: denominations[3] <= self.balance,
-            },
-            Chip {
-                amount: denominations[4],
-                text: format_chip_units(denominations[4].saturating_div(Amount::ONE)),
-                enable: denominations[4] <= self.balance,
-            },
-        ];
+        let chip_set = default_chip_set(base);
+
+        self.bet_data = Some(BetData {
+            min_bet: base,         // Smallest denomination
+            max_bet: self.balance, // Player's full balance
+            chipset: Some(generate_chip_list(&chip_set, self.balance)),
+        })
+    }
 
+    /// Bet data clamped to the table limits instead of derived from the balance alone.
+    pub fn calculate_table_bet_data(&mut self, stakes: &TableStakes) {
+        let affordable = self.balance.min(stakes.max_bet);
+        let chip_set = stakes.chip_set.clone().unwrap_or_else(|| default_chip_set(stakes.chip_base));
+
+        // A balance below the table minimum can't bet at all, the limits still never cross
         self.bet_data = Some(BetData {
-            min_bet: denominations[0], // Smallest denomination
-            max_bet: self.balance,     // Player's full balance
-            chipset: Some(generated_chip_list),
+            min_bet: stakes.min_bet,
+            max_bet: affordable.max(stakes.min_bet),
+            chipset: Some(generate_chip_list(&chip_set, affordable)),
         })
     }
 
     pub fn clear_bet_data(&mut self) {
         self.bet_data = None
     }
-}
 
-pub fn format_chip_units(value: u128) -> String {
-    if value < 1000 {
-        return value.to_string();
+    pub fn update_chip_stack(&mut self, chip_stack: Vec<ChipPlacement>) {
+        self.chip_stack = chip_stack
     }
 
-    const SUFFIXES: [(&str, u128); 11] = [
-        ("D", 1_000_000_000_000_000_000_000_000_000_000_000), // 10^33
-        ("N", 1_000_000_000_000_000_000_000_000_000_000),     // 10^30
-        ("O", 1_000_000_000_000_000_000_000_000_000),         // 10^27
-        ("Sp", 1_000_000_000_000_000_000_000_000),            // 10^24
-        ("S", 1_000_000_000_000_000_000_000),                 // 10^21
-        ("Qi", 1_000_000_000_000_000_000),                    // 10^18
-        ("Q", 1_000_000_000_000_000),                         // 10^15
-        ("T", 1_000_000_000_000),                             // 10^12
-        ("B", 1_000_000_000),                                 // 10^9
-        ("M", 1_000_000),                                     // 10^6
-        ("K", 1_000),                                         // 10^3
-    ];
-
-    for &(suffix, divisor) in SUFFIXES.iter() {
-        if value >= divisor {
-            let scaled = value as f64 / divisor as f64;
-            // Handle values that round up to 1000
-            if scaled >= 999.95 {
-                // Try next higher suffix
-                if let Some(&(next_suffix, next_divisor)) = SUFFIXES.get(SUFFIXES.len() - SUFFIXES.iter().position(|&s| s.1 == divisor).unwrap() - 1) {
-                    let next_scaled = value as f64 / next_divisor as f64;
-                    return format_chip_float(next_scaled, next_suffix);
-                }
-            }
-            return format_chip_float(scaled, suffix);
-        }
+    /// Keep the chips of the dealt bet for "repeat" and "double" on the next round.
+    pub fn commit_chip_stack(&mut self) {
+        self.last_chip_stack = std::mem::take(&mut self.chip_stack)
     }
+}
 
-    value.to_string()
+pub fn default_chip_set(base: Amount) -> Vec<ChipSpec> {
+    [
+        (base, "white"),                      // 1x
+        (base.saturating_mul(5), "red"),      // 5x
+        (base.saturating_mul(25), "green"),   // 25x
+        (base.saturating_mul(100), "black"),  // 100x
+        (base.saturating_mul(250), "purple"), // 250x
+    ]
+    .into_iter()
+    .map(|(amount, color)| ChipSpec {
+        amount,
+        label: None,
+        color: color.to_string(),
+    })
+    .collect()
 }
 
-fn format_chip_float(value: f64, suffix: &str) -> String {
-    // Round to the nearest tenth
-    let rounded = (value * 10.0).round() / 10.0;
+fn generate_chip_list(chip_set: &[ChipSpec], limit: Amount) -> Vec<Chip> {
+    chip_set
+        .iter()
+        .map(|spec| Chip {
+            amount: spec.amount,
+            text: spec.label.clone().unwrap_or_else(|| format_chip_units(spec.amount.saturating_div(Amount::ONE))),
+            color: spec.color.clone(),
+            enable: spec.amount <= limit,
+        })
+        .collect()
+}
+
+// Short scale suffixes in ascending order, every step is 10^3
+const SUFFIXES: [(&str, u128); 12] = [
+    ("", 1),
+    ("K", 1_000),                                         // 10^3
+    ("M", 1_000_000),                                     // 10^6
+    ("B", 1_000_000_000),                                 // 10^9
+    ("T", 1_000_000_000_000),                             // 10^12
+    ("Q", 1_000_000_000_000_000),                         // 10^15
+    ("Qi", 1_000_000_000_000_000_000),                    // 10^18
+    ("S", 1_000_000_000_000_000_000_000),                 // 10^21
+    ("Sp", 1_000_000_000_000_000_000_000_000),            // 10^24
+    ("O", 1_000_000_000_000_000_000_000_000_000),         // 10^27
+    ("N", 1_000_000_000_000_000_000_000_000_000_000),     // 10^30
+    ("D", 1_000_000_000_000_000_000_000_000_000_000_000), // 10^33
+];
+
+/// Format whole token units with a short scale suffix and one fraction digit, rounded half up.
+pub fn format_chip_units(value: u128) -> String {
+    let mut index = SUFFIXES.iter().rposition(|&(_, divisor)| value >= divisor).unwrap_or(0);
+
+    loop {
+        let (suffix, divisor) = SUFFIXES[index];
+        let (integer, tenths) = divide_rounded(value, divisor);
+
+        // Values that round up to 1000 move to the next suffix
+        if integer >= 1000 && index + 1 < SUFFIXES.len() {
+            index += 1;
+            continue;
+        }
+
+        if tenths == 0 {
+            return format!("{}{}", integer, suffix);
+        }
+        return format!("{}.{}{}", integer, tenths, suffix);
+    }
+}
 
-    if rounded.fract() == 0.0 {
-        format!("{:.0}{}", rounded, suffix)
-    } else {
-        let s = format!("{:.1}", rounded);
-        s.trim_end_matches('0').trim_end_matches('.').to_string() + suffix
+/// Exact division of `value` by `divisor` rounded half up to tenths, without going through floats.
+fn divide_rounded(value: u128, divisor: u128) -> (u128, u128) {
+    let integer = value / divisor;
+    let remainder = value % divisor;
+    // remainder < divisor <= 10^33, so scaling it by 20 can't overflow
+    let tenths = (remainder * 20 + divisor) / (divisor * 2);
+    if tenths == 10 {
+        return (integer + 1, 0);
     }
+    (integer, tenths)
 }
@@ -1,9 +1,9 @@
-use crate::bet_chip_profile::Profile;
-use crate::deck::{format_card, Deck};
+use crate::bet_chip_profile::{Profile, TableStakes};
+use crate::deck::{calculate_hand_value, format_card, is_soft_hand, Deck};
 use crate::player_dealer::{Dealer, Player};
 use async_graphql::scalar;
-use async_graphql_derive::SimpleObject;
-use linera_sdk::linera_base_types::{Amount, Timestamp};
+use async_graphql_derive::{InputObject, SimpleObject};
+use linera_sdk::linera_base_types::{Amount, BcsHashable, ChainId, CryptoHash, Timestamp};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -13,6 +13,39 @@ pub const MAX_BLACKJACK_PLAYERS: usize = 3;
 /// The stream name the application uses for events about blackjack game event.
 pub const BLACKJACK_STREAM_NAME: &[u8] = b"blackjack";
 
+/// The stream name the application uses for table chat and emotes.
+pub const CHAT_STREAM_NAME: &[u8] = b"blackjack_chat";
+
+/// A full game snapshot is emitted every this many table events, so late joiners can catch up.
+pub const TABLE_SNAPSHOT_INTERVAL: u64 = 20;
+
+/// Time a waitlisted user has to claim a freed seat before it goes to the next in line.
+pub const SEAT_OFFER_DURATION_IN_MICROS: u64 = 30 * 1_000_000;
+
+/// Longest seat waiting list a Play Chain keeps.
+pub const MAX_WAITLIST_LENGTH: usize = 20;
+
+/// A Play Chain with seated players and no heartbeat for this long is left out of matchmaking.
+pub const PLAY_CHAIN_STALE_DURATION_IN_MICROS: u64 = 5 * 60 * 1_000_000;
+
+/// Sends of a request, the first one included, before the User Chain gives up on a reply.
+pub const MAX_REQUEST_ATTEMPTS: u8 = 3;
+
+/// Time seated players have to bet once the first bet of a multi player round is placed.
+pub const TABLE_BET_DURATION_IN_MICROS: u64 = 30 * 1_000_000;
+
+/// Time a multi player seat has to hit or stand before it stands automatically.
+pub const TABLE_TURN_DURATION_IN_MICROS: u64 = 30 * 1_000_000;
+
+/// Private tables a User Chain can hold or have in flight at once.
+pub const MAX_PRIVATE_TABLES_PER_OWNER: usize = 2;
+
+/// Time a private table can stay without seated players before it is released.
+pub const PRIVATE_TABLE_IDLE_DURATION_IN_MICROS: u64 = 30 * 60 * 1_000_000;
+
+/// Shortest invite code accepted for a private table, the hash of a shorter one is easy to brute force.
+pub const MIN_INVITE_CODE_LENGTH: usize = 8;
+
 scalar!(BlackjackStatus);
 #[derive(Debug, Clone, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
 #[repr(u8)]
@@ -47,6 +80,8 @@ pub enum UserStatus {
     RequestTableSeatFail = 5,
     InMultiPlayerGame = 6,
     InSinglePlayerGame = 7,
+    Spectating = 8,
+    Waitlisted = 9,
 }
 
 scalar!(GameOutcome);
@@ -59,6 +94,317 @@ pub enum GameOutcome {
     None = 4,
 }
 
+scalar!(PlayerAction);
+#[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+#[repr(u8)]
+pub enum PlayerAction {
+    Hit = 0,
+    Stand = 1,
+}
+
+scalar!(PublicChainStatus);
+#[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+#[repr(u8)]
+pub enum PublicChainStatus {
+    Live = 0,
+    Draining = 1, // no new FindPlayChain or TokenPot traffic
+}
+
+/// A public chain can only stop being live while another live public chain is left,
+/// user chains always need one to route FindPlayChain and token pots.
+pub fn check_public_chain_retirement(live_chains: &[ChainId], chain_id: ChainId) -> Result<(), String> {
+    if live_chains.len() == 1 && live_chains.contains(&chain_id) {
+        return Err(format!("public chain {:?} is the last live public chain", chain_id));
+    }
+    Ok(())
+}
+
+scalar!(PlayChainMode);
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+#[repr(u8)]
+pub enum PlayChainMode {
+    #[default]
+    Open = 0,
+    Paused = 1,   // no new seats, seated players wait
+    Draining = 2, // current round finishes, then no new seats
+    Private = 3,  // reserved, seats need the invite code
+}
+
+scalar!(RuleVariant);
+#[derive(Debug, Clone, Copy, Default, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+#[repr(u8)]
+pub enum RuleVariant {
+    #[default]
+    Standard = 0, // dealer stands on all 17s
+    DealerHitsSoft17 = 1, // dealer draws to a soft 17
+}
+
+impl RuleVariant {
+    /// Whether the dealer takes another card with the current hand.
+    pub fn dealer_draws(&self, dealer: &Dealer) -> bool {
+        let value = calculate_hand_value(&dealer.hand);
+        match self {
+            RuleVariant::Standard => value < 17,
+            RuleVariant::DealerHitsSoft17 => value < 17 || (value == 17 && is_soft_hand(&dealer.hand)),
+        }
+    }
+}
+
+/// Matchmaking filters for FindPlayChain, unset fields match any table.
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "TableFilterInput")]
+pub struct TableFilter {
+    pub min_stake: Option<Amount>,         // lowest acceptable table min bet
+    pub max_stake: Option<Amount>,         // highest acceptable table min bet
+    pub rule_variant: Option<RuleVariant>, // required rule variant
+    pub seat_count: Option<u8>,            // preferred number of players already seated
+    pub friend_table: Option<ChainId>,     // only match this Play Chain
+}
+
+impl TableFilter {
+    pub fn matches(&self, chain_id: ChainId, listing: &TableListing) -> bool {
+        if self.friend_table.is_some_and(|table| table != chain_id) {
+            return false;
+        }
+        if self.rule_variant.is_some_and(|rules| rules != listing.rules) {
+            return false;
+        }
+        if self.min_stake.is_none() && self.max_stake.is_none() {
+            return true;
+        }
+
+        // Tables without stakes only match searches without a stake range
+        match &listing.stakes {
+            Some(stakes) => self.min_stake.is_none_or(|min| stakes.min_bet >= min) && self.max_stake.is_none_or(|max| stakes.min_bet <= max),
+            None => false,
+        }
+    }
+
+    /// Distance from the preferred seat count, lower is better.
+    pub fn seat_preference(&self, player_number: u8) -> u8 {
+        self.seat_count.map_or(0, |count| count.abs_diff(player_number))
+    }
+}
+
+/// What a public chain knows about a listed Play Chain besides its occupancy.
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct TableListing {
+    pub stakes: Option<TableStakes>,
+    pub rules: RuleVariant,
+    pub mode: PlayChainMode,
+    pub last_assigned: u64, // assignment sequence of the last match, 0 if never assigned
+}
+
+/// Pick the open matching table closest to the preferred seat count,
+/// least recently assigned first so players spread across tables.
+pub fn select_play_chain(filter: &TableFilter, candidates: &[(ChainId, u8, TableListing)]) -> Option<ChainId> {
+    candidates
+        .iter()
+        .filter(|(chain_id, _, listing)| listing.mode == PlayChainMode::Open && filter.matches(*chain_id, listing))
+        .min_by_key(|(_, player_number, listing)| (filter.seat_preference(*player_number), listing.last_assigned))
+        .map(|(chain_id, _, _)| *chain_id)
+}
+
+/// Progress a Play Chain reports to its public chain.
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct PlayChainHeartbeat {
+    pub sequence: u64,
+    pub status: BlackjackStatus,
+    pub player_number: u8,
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct PlayChainHealth {
+    pub chain_id: ChainId,
+    pub heartbeat: PlayChainHeartbeat,
+    pub last_seen: Timestamp,
+    pub stale: bool, // computed when queried
+}
+
+impl PlayChainHealth {
+    pub fn new(chain_id: ChainId, heartbeat: PlayChainHeartbeat, last_seen: Timestamp) -> Self {
+        PlayChainHealth {
+            chain_id,
+            heartbeat,
+            last_seen,
+            stale: false,
+        }
+    }
+
+    /// Seated tables heartbeat as hands are played, an empty table has nothing to report and stays listed.
+    pub fn is_stale(&self, now: Timestamp) -> bool {
+        self.heartbeat.player_number > 0 && now.delta_since(self.last_seen).as_micros() > PLAY_CHAIN_STALE_DURATION_IN_MICROS
+    }
+}
+
+/// Invite code salted with the Play Chain it opens, so the same code hashes differently per table.
+#[derive(Debug, Deserialize, Serialize)]
+struct InviteCode {
+    play_chain_id: ChainId,
+    code: String,
+}
+
+impl BcsHashable<'_> for InviteCode {}
+
+pub fn validate_invite_code(invite_code: &str) -> Result<(), String> {
+    if invite_code.trim().chars().count() < MIN_INVITE_CODE_LENGTH {
+        return Err(format!("invite code must have at least {} characters", MIN_INVITE_CODE_LENGTH));
+    }
+    Ok(())
+}
+
+pub fn hash_invite_code(play_chain_id: ChainId, invite_code: &str) -> CryptoHash {
+    CryptoHash::new(&InviteCode {
+        play_chain_id,
+        code: invite_code.to_string(),
+    })
+}
+
+/// Reservation of a Play Chain as a private table.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct PrivateTable {
+    pub owner: ChainId,
+    #[graphql(skip)]
+    pub invite_code_hash: CryptoHash,
+    pub allowlist: Vec<ChainId>, // chains allowed to take a seat, empty allows anyone with the code
+}
+
+impl PrivateTable {
+    pub fn is_valid_code(&self, play_chain_id: ChainId, invite_code: &str) -> bool {
+        hash_invite_code(play_chain_id, invite_code) == self.invite_code_hash
+    }
+
+    pub fn is_allowed(&self, chain_id: ChainId) -> bool {
+        self.allowlist.is_empty() || self.allowlist.contains(&chain_id)
+    }
+
+    /// `active_at` is the last reservation, guest entry or heartbeat with a seated player.
+    pub fn is_idle(active_at: Timestamp, now: Timestamp) -> bool {
+        now.delta_since(active_at).as_micros() > PRIVATE_TABLE_IDLE_DURATION_IN_MICROS
+    }
+}
+
+/// What a user shares to reserve a private table, the code is hashed before it leaves the chain.
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "PrivateTableInviteInput")]
+pub struct PrivateTableInvite {
+    pub invite_code: String,
+    pub allowlist: Vec<ChainId>,
+}
+
+/// Request of a User Chain that is waiting for a reply from another chain.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct PendingRequest {
+    pub request_id: u64,
+    pub deadline: Timestamp,
+    pub attempts: u8,
+}
+
+impl PendingRequest {
+    pub fn new(request_id: u64, current_time_micros: u64, duration_micros: u64) -> Self {
+        PendingRequest {
+            request_id,
+            deadline: Timestamp::from(current_time_micros.saturating_add(duration_micros)),
+            attempts: 1,
+        }
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        now > self.deadline
+    }
+
+    pub fn can_resend(&self) -> bool {
+        self.attempts < MAX_REQUEST_ATTEMPTS
+    }
+
+    /// Send the same request again, a reply to any attempt still matches the request id.
+    pub fn resend(&mut self, current_time_micros: u64, duration_micros: u64) {
+        self.attempts = self.attempts.saturating_add(1);
+        self.deadline = Timestamp::from(current_time_micros.saturating_add(duration_micros));
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct WaitlistEntry {
+    pub chain_id: ChainId,
+    pub seat_id: Option<u8>, // None waits for any seat
+    pub joined_at: Timestamp,
+}
+
+/// Freed seat held for a waitlisted user until `deadline`.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct SeatOffer {
+    pub chain_id: ChainId,
+    pub seat_id: u8,
+    pub deadline: Timestamp,
+}
+
+impl SeatOffer {
+    pub fn new(chain_id: ChainId, seat_id: u8, current_time_micros: u64) -> Self {
+        SeatOffer {
+            chain_id,
+            seat_id,
+            deadline: Timestamp::from(current_time_micros.saturating_add(SEAT_OFFER_DURATION_IN_MICROS)),
+        }
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        now > self.deadline
+    }
+}
+
+/// Index of the first waiting user that takes `seat_id` and holds no other offer.
+pub fn next_waitlist_match(waitlist: &[WaitlistEntry], seat_id: u8, offered: &[ChainId]) -> Option<usize> {
+    waitlist
+        .iter()
+        .position(|entry| entry.seat_id.is_none_or(|wanted| wanted == seat_id) && !offered.contains(&entry.chain_id))
+}
+
+/// Answer of a Play Chain to a RequestTableSeat.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct TableSeatResult {
+    pub seat_id: u8,
+    pub success: bool,
+    pub stakes: Option<TableStakes>,
+}
+
+/// Result of one seat when a multi player round is settled.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct SeatSettlement {
+    pub seat_id: u8,
+    pub outcome: GameOutcome,
+    pub bet: Amount,
+    pub payout: Amount, // returned to the player balance, bet included
+}
+
+/// Outcome of a finished player hand value against the final dealer hand value.
+pub fn seat_outcome(player_value: u8, dealer_value: u8) -> GameOutcome {
+    if player_value > 21 {
+        GameOutcome::DealerWins
+    } else if dealer_value > 21 || player_value > dealer_value {
+        GameOutcome::PlayerWins
+    } else if dealer_value > player_value {
+        GameOutcome::DealerWins
+    } else {
+        GameOutcome::Draw
+    }
+}
+
+/// Results of one multi player round, sent as-is to every seated User Chain.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct RoundSettlement {
+    pub play_chain_id: ChainId,
+    pub round: u64,                    // sequence of the RoundSettled event
+    pub public_chain: Option<ChainId>, // receives the lost bets and covers the winnings
+    pub results: Vec<SeatSettlement>,
+}
+
+impl RoundSettlement {
+    pub fn result_for(&self, seat_id: u8) -> Option<&SeatSettlement> {
+        self.results.iter().find(|result| result.seat_id == seat_id)
+    }
+}
+
 #[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
 pub struct GameData {
     pub profile: Profile,
@@ -101,6 +447,9 @@ impl BlackjackGame {
 
     pub fn register_update_player(&mut self, seat_id: u8, player: Player) {
         self.players.insert(seat_id, player);
+        if self.status == BlackjackStatus::WaitingForPlayer {
+            self.update_status(BlackjackStatus::WaitingForBets);
+        }
     }
 
     pub fn update_status(&mut self, new_status: BlackjackStatus) {
@@ -118,6 +467,164 @@ impl BlackjackGame {
         }
     }
 
+    pub fn is_hand_in_play(&self) -> bool {
+        self.status == BlackjackStatus::PlayerTurn || self.status == BlackjackStatus::DealerTurn
+    }
+
+    /// Free a seat and return the bet forfeited by leaving mid-hand.
+    /// A bet that was not dealt yet is returned to the player, so nothing is forfeited.
+    pub fn release_seat(&mut self, seat_id: u8) -> Amount {
+        let forfeited = match self.players.get(&seat_id) {
+            Some(player) if self.is_hand_in_play() => player.bet,
+            _ => Amount::ZERO,
+        };
+        self.remove_player(seat_id);
+
+        if self.players.is_empty() {
+            self.update_status(BlackjackStatus::WaitingForPlayer);
+            self.time_limit = None;
+        } else if self.is_turn_of(seat_id) {
+            self.advance_turn();
+        }
+        forfeited
+    }
+
+    /// A bet after a settled round opens the next betting round.
+    pub fn place_bet(&mut self, seat_id: u8, amount: Amount) {
+        if self.status == BlackjackStatus::RoundEnded {
+            self.update_status(BlackjackStatus::WaitingForBets);
+        }
+        if let Some(player) = self.players.get_mut(&seat_id) {
+            player.bet = amount;
+        }
+    }
+
+    pub fn has_bets(&self) -> bool {
+        self.players.values().any(|player| player.bet > Amount::ZERO)
+    }
+
+    /// Start dealing a multi player round, every bet moves from the seat balance into the pot.
+    pub fn start_round(&mut self) {
+        self.dealer = Dealer::empty();
+        for player in self.players.values_mut() {
+            player.hand.clear();
+            player.balance = player.balance.saturating_sub(player.bet);
+            self.pot = self.pot.saturating_add(player.bet);
+        }
+        self.active_seat = 0;
+        self.update_status(BlackjackStatus::PlayerTurn);
+    }
+
+    /// Seats dealt into the round in dealing order.
+    pub fn betting_seats(&self) -> Vec<u8> {
+        let mut seats: Vec<u8> = self
+            .players
+            .values()
+            .filter(|player| player.bet > Amount::ZERO)
+            .map(|player| player.seat_id)
+            .collect();
+        seats.sort();
+        seats
+    }
+
+    /// Add a dealt card to a player hand, or to the dealer hand when `seat_id` is None.
+    /// During a multi player round, the second dealer card ends the initial deal and the turn
+    /// moves on once the active hand reaches 21 or busts.
+    pub fn add_card(&mut self, seat_id: Option<u8>, card: u8) {
+        match seat_id {
+            Some(seat_id) => {
+                if let Some(player) = self.players.get_mut(&seat_id) {
+                    player.hand.push(card);
+                }
+            }
+            None => self.dealer.hand.push(card),
+        }
+        self.count = self.count.saturating_sub(1);
+
+        if self.status != BlackjackStatus::PlayerTurn {
+            return;
+        }
+        let initial_deal_done = self.active_seat == 0 && seat_id.is_none() && self.dealer.hand.len() == 2;
+        let active_hand_done = seat_id.is_some_and(|seat_id| seat_id == self.active_seat && !self.can_play(seat_id));
+        if initial_deal_done || active_hand_done {
+            self.advance_turn();
+        }
+    }
+
+    fn can_play(&self, seat_id: u8) -> bool {
+        self.players
+            .get(&seat_id)
+            .is_some_and(|player| player.bet > Amount::ZERO && calculate_hand_value(&player.hand) < 21)
+    }
+
+    /// Pass the turn to the next seat still playing, or to the dealer once every seat has played.
+    pub fn advance_turn(&mut self) {
+        let next_seat = self
+            .betting_seats()
+            .into_iter()
+            .find(|seat_id| *seat_id > self.active_seat && self.can_play(*seat_id));
+        match next_seat {
+            Some(seat_id) => self.active_seat = seat_id,
+            None => {
+                self.active_seat = 0;
+                self.update_status(BlackjackStatus::DealerTurn);
+            }
+        }
+        for player in self.players.values_mut() {
+            player.current_player = player.seat_id == self.active_seat;
+        }
+    }
+
+    /// Whether `seat_id` may hit or stand now.
+    pub fn is_turn_of(&self, seat_id: u8) -> bool {
+        self.status == BlackjackStatus::PlayerTurn && self.active_seat == seat_id
+    }
+
+    pub fn stand(&mut self, seat_id: u8) {
+        if self.is_turn_of(seat_id) {
+            self.advance_turn();
+        }
+    }
+
+    /// Settle every seat with a bet against the dealer hand, ordered by seat.
+    pub fn round_settlements(&self) -> Vec<SeatSettlement> {
+        let dealer_value = calculate_hand_value(&self.dealer.hand);
+        let mut results: Vec<SeatSettlement> = self
+            .players
+            .values()
+            .filter(|player| player.bet > Amount::ZERO)
+            .map(|player| {
+                let outcome = seat_outcome(calculate_hand_value(&player.hand), dealer_value);
+                let payout = match outcome {
+                    GameOutcome::PlayerWins => player.bet.saturating_mul(2),
+                    GameOutcome::Draw => player.bet,
+                    _ => Amount::ZERO,
+                };
+                SeatSettlement {
+                    seat_id: player.seat_id,
+                    outcome,
+                    bet: player.bet,
+                    payout,
+                }
+            })
+            .collect();
+        results.sort_by_key(|result| result.seat_id);
+        results
+    }
+
+    pub fn settle_round(&mut self, results: &[SeatSettlement]) {
+        for result in results {
+            if let Some(player) = self.players.get_mut(&result.seat_id) {
+                player.balance = player.balance.saturating_add(result.payout);
+                player.reset_bet();
+                player.current_player = false;
+            }
+        }
+        self.pot = Amount::ZERO;
+        self.time_limit = None;
+        self.update_status(BlackjackStatus::RoundEnded);
+    }
+
     pub fn draw_initial_cards(&mut self, seat_id: u8) {
         // Deal 2 cards to the dealer
         for _ in 0..2 {
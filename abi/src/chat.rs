@@ -0,0 +1,55 @@
+use async_graphql::scalar;
+use async_graphql_derive::SimpleObject;
+use linera_sdk::linera_base_types::{ChainId, Timestamp};
+use serde::{Deserialize, Serialize};
+
+/// Longest chat message a Play Chain relays, in characters.
+pub const MAX_CHAT_MESSAGE_LENGTH: usize = 200;
+
+/// Minimum time between two chat messages or emotes from the same chain.
+pub const CHAT_RATE_LIMIT_IN_MICROS: u64 = 2 * 1_000_000;
+
+/// Number of recent chat entries kept in the history.
+pub const CHAT_HISTORY_SIZE: usize = 50;
+
+scalar!(Emote);
+#[derive(Debug, Clone, Copy, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+#[repr(u8)]
+pub enum Emote {
+    ThumbsUp = 0,
+    Laugh = 1,
+    Wow = 2,
+    Sad = 3,
+    Clap = 4,
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct ChatEntry {
+    pub sender: ChainId,
+    pub seat_id: Option<u8>, // None for spectators
+    pub text: String,        // empty for emotes
+    pub emote: Option<Emote>,
+    pub sent_at: Timestamp,
+}
+
+/// Trim a chat message and drop control characters, None when empty or too long.
+pub fn sanitize_chat_text(text: &str) -> Option<String> {
+    let text: String = text.trim().chars().filter(|c| !c.is_control()).collect();
+    if text.is_empty() || text.chars().count() > MAX_CHAT_MESSAGE_LENGTH {
+        return None;
+    }
+    Some(text)
+}
+
+pub fn is_chat_rate_limited(last_sent: Option<Timestamp>, now: Timestamp) -> bool {
+    last_sent.is_some_and(|last_sent| now.delta_since(last_sent).as_micros() < CHAT_RATE_LIMIT_IN_MICROS)
+}
+
+/// Append an entry, dropping the oldest ones past `CHAT_HISTORY_SIZE`.
+pub fn push_chat_history(history: &mut Vec<ChatEntry>, entry: ChatEntry) {
+    history.push(entry);
+    if history.len() > CHAT_HISTORY_SIZE {
+        let overflow = history.len() - CHAT_HISTORY_SIZE;
+        history.drain(..overflow);
+    }
+}
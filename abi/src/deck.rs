@@ -105,6 +105,13 @@ pub fn calculate_hand_value(hand: &Vec<u8>) -> u8 {
     total
 }
 
+/// Whether a hand still counts an Ace as 11, e.g. Ace and 6 is a soft 17.
+pub fn is_soft_hand(hand: &[u8]) -> bool {
+    let hard_total: u8 = hand.iter().map(|card| (((card - 1) % 13) + 1).min(10)).sum();
+    let has_ace = hand.iter().any(|card| (card - 1) % 13 == 0);
+    has_ace && hard_total + 10 <= 21
+}
+
 /// Format a card value (1-52) into a human-readable string.
 ///
 /// # Examples:
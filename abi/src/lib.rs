@@ -1,6 +1,8 @@
 pub mod bet_chip_profile;
 pub mod blackjack;
+pub mod chat;
 pub mod deck;
 pub mod player_dealer;
 pub mod poker;
 pub mod random;
+pub mod schema;
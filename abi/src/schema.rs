@@ -0,0 +1,37 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Schema version of the messages and events this bytecode sends.
+/// Bump it whenever a variant or a field is added to a versioned enum.
+/// Bare payloads come from the bytecode before envelopes, which had no schema version.
+pub const SCHEMA_VERSION: u16 = 1;
+
+/// A message or event as BCS bytes, tagged with the schema version of the sender.
+/// Chains running older bytecode can then tell a newer payload from a corrupt one.
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Envelope {
+    pub version: u16,
+    pub payload: Vec<u8>,
+}
+
+impl Envelope {
+    pub fn seal<T: Serialize>(value: &T) -> Self {
+        Envelope {
+            version: SCHEMA_VERSION,
+            payload: bcs::to_bytes(value).expect("Failed to serialize versioned payload"),
+        }
+    }
+
+    /// Decode the payload with the current layout, None when it does not decode, e.g. it comes from a newer schema.
+    /// Older schemas decode too as long as the layout only grew.
+    /// A payload that does not decode is skipped, so one bad message can't block the inbox of a chain.
+    pub fn open<T: DeserializeOwned>(&self) -> Option<T> {
+        match bcs::from_bytes(&self.payload) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                log::info!("Skipping payload from schema version {}: {}", self.version, error);
+                None
+            }
+        }
+    }
+}
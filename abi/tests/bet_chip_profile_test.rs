@@ -0,0 +1,258 @@
+use abi::bet_chip_profile::{chip_stack_value, double_chip_stack, ChipPlacement, ChipSpec, Profile, TableStakes, BUY_IN_MAX_BETS};
+use linera_sdk::linera_base_types::Amount;
+
+fn low_stakes_table() -> TableStakes {
+    TableStakes {
+        min_bet: Amount::from_tokens(10),
+        max_bet: Amount::from_tokens(500),
+        chip_base: Amount::from_tokens(10),
+        chip_set: None,
+    }
+}
+
+fn profile_with_balance(tokens: u128) -> Profile {
+    Profile {
+        seat: None,
+        balance: Amount::from_tokens(tokens),
+        bet_data: None,
+        chip_stack: vec![],
+        last_chip_stack: vec![],
+    }
+}
+
+#[test]
+fn test_balance_derived_min_bet() {
+    let mut profile = profile_with_balance(1_000);
+    profile.calculate_bet_data();
+    let bet_data = profile.bet_data.unwrap();
+    assert_eq!(bet_data.min_bet, Amount::from_tokens(100));
+    assert_eq!(bet_data.max_bet, Amount::from_tokens(1_000));
+}
+
+#[test]
+fn test_table_max_bet_clamps_whale_balance() {
+    let mut profile = profile_with_balance(10_000_000);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    assert_eq!(bet_data.min_bet, Amount::from_tokens(10));
+    assert_eq!(bet_data.max_bet, Amount::from_tokens(500));
+}
+
+#[test]
+fn test_table_max_bet_clamps_to_small_balance() {
+    let mut profile = profile_with_balance(200);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    assert_eq!(bet_data.min_bet, Amount::from_tokens(10));
+    assert_eq!(bet_data.max_bet, Amount::from_tokens(200));
+}
+
+#[test]
+fn test_table_chip_ladder_uses_chip_base() {
+    let mut profile = profile_with_balance(10_000_000);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let chipset = profile.bet_data.unwrap().chipset.unwrap();
+    let amounts: Vec<Amount> = chipset.iter().map(|chip| chip.amount).collect();
+    assert_eq!(
+        amounts,
+        vec![
+            Amount::from_tokens(10),
+            Amount::from_tokens(50),
+            Amount::from_tokens(250),
+            Amount::from_tokens(1_000),
+            Amount::from_tokens(2_500),
+        ]
+    );
+}
+
+#[test]
+fn test_table_chips_above_max_bet_are_disabled() {
+    let mut profile = profile_with_balance(10_000_000);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let chipset = profile.bet_data.unwrap().chipset.unwrap();
+    let enabled: Vec<bool> = chipset.iter().map(|chip| chip.enable).collect();
+    assert_eq!(enabled, vec![true, true, true, false, false]);
+}
+
+#[test]
+fn test_max_buy_in_follows_max_bet() {
+    assert_eq!(low_stakes_table().max_buy_in(), Amount::from_tokens(500 * BUY_IN_MAX_BETS));
+
+    let unbounded = TableStakes {
+        max_bet: Amount::MAX,
+        ..low_stakes_table()
+    };
+    assert_eq!(unbounded.max_buy_in(), Amount::MAX);
+}
+
+#[test]
+fn test_table_stakes_validation() {
+    assert!(low_stakes_table().validate().is_ok());
+
+    let inverted = TableStakes {
+        min_bet: Amount::from_tokens(500),
+        max_bet: Amount::from_tokens(10),
+        chip_base: Amount::from_tokens(10),
+        chip_set: None,
+    };
+    assert!(inverted.validate().is_err());
+
+    let no_chip = TableStakes {
+        min_bet: Amount::from_tokens(10),
+        max_bet: Amount::from_tokens(500),
+        chip_base: Amount::ZERO,
+        chip_set: None,
+    };
+    assert!(no_chip.validate().is_err());
+
+    let chip_above_max_bet = TableStakes {
+        min_bet: Amount::from_tokens(10),
+        max_bet: Amount::from_tokens(20),
+        chip_base: Amount::from_tokens(25),
+        chip_set: None,
+    };
+    assert!(chip_above_max_bet.validate().is_err());
+
+    let min_bet_below_chip = TableStakes {
+        min_bet: Amount::from_tokens(5),
+        max_bet: Amount::from_tokens(500),
+        chip_base: Amount::from_tokens(10),
+        chip_set: None,
+    };
+    assert!(min_bet_below_chip.validate().is_err());
+}
+
+#[test]
+fn test_custom_chip_set_validated_against_limits() {
+    let stakes = TableStakes {
+        min_bet: Amount::from_tokens(5),
+        max_bet: Amount::from_tokens(500),
+        chip_base: Amount::from_tokens(10),
+        chip_set: Some(vec![ChipSpec {
+            amount: Amount::from_tokens(5),
+            label: None,
+            color: "blue".to_string(),
+        }]),
+    };
+    // The custom chips replace chip_base, a 5 chip fits a min bet of 5
+    assert_eq!(stakes.smallest_chip(), Amount::from_tokens(5));
+    assert!(stakes.validate().is_ok());
+}
+
+#[test]
+fn test_table_bet_data_below_min_bet() {
+    let mut profile = profile_with_balance(5);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    assert_eq!(bet_data.min_bet, Amount::from_tokens(10));
+    assert_eq!(bet_data.max_bet, Amount::from_tokens(10));
+    assert!(bet_data.chipset.unwrap().iter().all(|chip| !chip.enable));
+}
+
+fn chips(placements: &[(u128, u32)]) -> Vec<ChipPlacement> {
+    placements
+        .iter()
+        .map(|&(tokens, count)| ChipPlacement {
+            amount: Amount::from_tokens(tokens),
+            count,
+        })
+        .collect()
+}
+
+#[test]
+fn test_custom_chip_set_of_any_length() {
+    let stakes = TableStakes {
+        chip_set: Some(vec![
+            ChipSpec {
+                amount: Amount::from_tokens(1),
+                label: Some("One".to_string()),
+                color: "blue".to_string(),
+            },
+            ChipSpec {
+                amount: Amount::from_tokens(20),
+                label: None,
+                color: "yellow".to_string(),
+            },
+        ]),
+        ..low_stakes_table()
+    };
+    let mut profile = profile_with_balance(10_000);
+    profile.calculate_table_bet_data(&stakes);
+    let chipset = profile.bet_data.unwrap().chipset.unwrap();
+    assert_eq!(chipset.len(), 2);
+    assert_eq!(chipset[0].text, "One");
+    assert_eq!(chipset[0].color, "blue");
+    assert_eq!(chipset[1].text, "20");
+    assert_eq!(chipset[1].color, "yellow");
+}
+
+#[test]
+fn test_empty_custom_chip_set_is_invalid() {
+    let stakes = TableStakes {
+        chip_set: Some(vec![]),
+        ..low_stakes_table()
+    };
+    assert!(stakes.validate().is_err());
+}
+
+#[test]
+fn test_chip_stack_total() {
+    let mut profile = profile_with_balance(10_000_000);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    assert_eq!(bet_data.chip_stack_total(&chips(&[(10, 2), (50, 1)])), Ok(Amount::from_tokens(70)));
+    assert_eq!(bet_data.chip_stack_total(&[]), Ok(Amount::ZERO));
+}
+
+#[test]
+fn test_chip_stack_rejects_disabled_or_unknown_chip() {
+    let mut profile = profile_with_balance(10_000_000);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    // 1000 exceeds the table max bet and is disabled
+    assert!(bet_data.chip_stack_total(&chips(&[(1_000, 1)])).is_err());
+    // 7 is not part of the chip set
+    assert!(bet_data.chip_stack_total(&chips(&[(7, 1)])).is_err());
+}
+
+#[test]
+fn test_chip_stack_rebuilt_from_amount() {
+    let mut profile = profile_with_balance(10_000_000);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    assert_eq!(bet_data.chip_stack_for(Amount::from_tokens(320)), Ok(chips(&[(250, 1), (50, 1), (10, 2)])));
+    assert_eq!(bet_data.chip_stack_for(Amount::ZERO), Ok(vec![]));
+    // 325 leaves a remainder of 5 below the smallest chip
+    assert!(bet_data.chip_stack_for(Amount::from_tokens(325)).is_err());
+}
+
+#[test]
+fn test_chip_stack_rebuilt_without_disabled_chips() {
+    // A 250 chip from an earlier bet is disabled once the balance drops to 300
+    let last_chip_stack = chips(&[(250, 1), (10, 2)]);
+    let mut profile = profile_with_balance(300);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    assert!(bet_data.chip_stack_total(&last_chip_stack).is_ok());
+
+    let mut profile = profile_with_balance(200);
+    profile.calculate_table_bet_data(&low_stakes_table());
+    let bet_data = profile.bet_data.unwrap();
+    assert!(bet_data.chip_stack_total(&last_chip_stack).is_err());
+    assert_eq!(chip_stack_value(&last_chip_stack), Amount::from_tokens(270));
+    assert_eq!(bet_data.chip_stack_for(Amount::from_tokens(270)), Ok(chips(&[(50, 5), (10, 2)])));
+}
+
+#[test]
+fn test_double_chip_stack() {
+    assert_eq!(double_chip_stack(&chips(&[(10, 2), (50, 1)])), chips(&[(10, 4), (50, 2)]));
+}
+
+#[test]
+fn test_commit_chip_stack_keeps_last_bet() {
+    let mut profile = profile_with_balance(1_000);
+    profile.update_chip_stack(chips(&[(10, 3)]));
+    profile.commit_chip_stack();
+    assert!(profile.chip_stack.is_empty());
+    assert_eq!(profile.last_chip_stack, chips(&[(10, 3)]));
+}
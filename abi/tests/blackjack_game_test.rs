@@ -0,0 +1,326 @@
+use abi::blackjack::{BlackjackGame, BlackjackStatus, GameOutcome, RuleVariant, SeatSettlement};
+use abi::deck::Deck;
+use abi::player_dealer::Player;
+use linera_sdk::linera_base_types::Amount;
+
+fn seated_player(seat_id: u8, bet: u128) -> Player {
+    Player {
+        seat_id,
+        bet: Amount::from_tokens(bet),
+        balance: Amount::from_tokens(1_000),
+        ..Player::default()
+    }
+}
+
+fn game_with_players(status: BlackjackStatus) -> BlackjackGame {
+    let mut game = BlackjackGame::new(Deck::empty());
+    game.register_update_player(1, seated_player(1, 50));
+    game.register_update_player(2, seated_player(2, 20));
+    game.update_status(status);
+    game
+}
+
+#[test]
+fn test_release_seat_between_hands_forfeits_nothing() {
+    let mut game = game_with_players(BlackjackStatus::WaitingForBets);
+    assert_eq!(game.release_seat(1), Amount::ZERO);
+    assert!(!game.is_seat_taken(1));
+    assert!(game.is_seat_taken(2));
+    assert_eq!(game.status, BlackjackStatus::WaitingForBets);
+}
+
+#[test]
+fn test_release_seat_mid_hand_forfeits_bet() {
+    let mut game = game_with_players(BlackjackStatus::PlayerTurn);
+    assert_eq!(game.release_seat(1), Amount::from_tokens(50));
+    assert!(!game.is_seat_taken(1));
+    assert_eq!(game.status, BlackjackStatus::PlayerTurn);
+}
+
+#[test]
+fn test_release_last_seat_resets_table() {
+    let mut game = game_with_players(BlackjackStatus::DealerTurn);
+    game.release_seat(1);
+    assert_eq!(game.release_seat(2), Amount::from_tokens(20));
+    assert!(game.players.is_empty());
+    assert_eq!(game.status, BlackjackStatus::WaitingForPlayer);
+}
+
+#[test]
+fn test_release_empty_seat() {
+    let mut game = game_with_players(BlackjackStatus::PlayerTurn);
+    assert_eq!(game.release_seat(3), Amount::ZERO);
+    assert_eq!(game.players.len(), 2);
+}
+
+#[test]
+fn test_add_card_to_player_and_dealer() {
+    let mut game = game_with_players(BlackjackStatus::PlayerTurn);
+    game.count = 10;
+    game.add_card(Some(1), 12);
+    game.add_card(None, 0);
+    game.add_card(Some(3), 5); // empty seat, card is only counted
+    assert_eq!(game.players[&1].hand, vec![12]);
+    assert_eq!(game.dealer.hand, vec![0]);
+    assert_eq!(game.count, 7);
+}
+
+#[test]
+fn test_settle_round_pays_out_and_clears_bets() {
+    let mut game = game_with_players(BlackjackStatus::DealerTurn);
+    game.place_bet(2, Amount::from_tokens(30));
+    let results = vec![
+        SeatSettlement {
+            seat_id: 1,
+            outcome: GameOutcome::PlayerWins,
+            bet: Amount::from_tokens(50),
+            payout: Amount::from_tokens(100),
+        },
+        SeatSettlement {
+            seat_id: 2,
+            outcome: GameOutcome::DealerWins,
+            bet: Amount::from_tokens(30),
+            payout: Amount::ZERO,
+        },
+    ];
+    game.settle_round(&results);
+    assert_eq!(game.players[&1].balance, Amount::from_tokens(1_100));
+    assert_eq!(game.players[&2].balance, Amount::from_tokens(1_000));
+    assert!(game.players.values().all(|player| player.bet == Amount::ZERO));
+    assert_eq!(game.status, BlackjackStatus::RoundEnded);
+}
+
+#[test]
+fn test_seating_and_betting_open_the_betting_round() {
+    let mut game = BlackjackGame::new(Deck::empty());
+    assert_eq!(game.status, BlackjackStatus::WaitingForPlayer);
+    game.register_update_player(
+        1,
+        Player {
+            seat_id: 1,
+            ..Player::default()
+        },
+    );
+    assert_eq!(game.status, BlackjackStatus::WaitingForBets);
+
+    game.update_status(BlackjackStatus::RoundEnded);
+    game.place_bet(1, Amount::from_tokens(10));
+    assert_eq!(game.status, BlackjackStatus::WaitingForBets);
+    assert_eq!(game.players[&1].bet, Amount::from_tokens(10));
+}
+
+#[test]
+fn test_deal_round_moves_bets_and_turns() {
+    let mut game = BlackjackGame::new(Deck::empty());
+    game.register_update_player(
+        1,
+        Player {
+            seat_id: 1,
+            bet: Amount::from_tokens(50),
+            balance: Amount::from_tokens(1_000),
+            ..Player::default()
+        },
+    );
+    game.register_update_player(
+        2,
+        Player {
+            seat_id: 2,
+            balance: Amount::from_tokens(1_000),
+            ..Player::default()
+        },
+    );
+    game.register_update_player(
+        3,
+        Player {
+            seat_id: 3,
+            bet: Amount::from_tokens(20),
+            balance: Amount::from_tokens(100),
+            ..Player::default()
+        },
+    );
+    game.count = 20;
+
+    game.start_round();
+    assert_eq!(game.status, BlackjackStatus::PlayerTurn);
+    assert_eq!(game.betting_seats(), vec![1, 3]);
+    assert_eq!(game.players[&1].balance, Amount::from_tokens(950));
+    assert_eq!(game.players[&3].balance, Amount::from_tokens(80));
+    assert_eq!(game.pot, Amount::from_tokens(70));
+
+    game.add_card(Some(1), 10);
+    game.add_card(Some(3), 1);
+    game.add_card(None, 5);
+    game.add_card(Some(1), 7);
+    game.add_card(Some(3), 13);
+    assert_eq!(game.active_seat, 0);
+    game.add_card(None, 0); // face down, ends the initial deal
+
+    // seat 3 has a blackjack, seat 1 plays first
+    assert!(game.is_turn_of(1));
+    assert!(game.players[&1].current_player);
+    assert!(!game.is_turn_of(2));
+
+    // a bust passes the turn, seat 3 has nothing left to play
+    game.add_card(Some(1), 5);
+    assert_eq!(game.status, BlackjackStatus::DealerTurn);
+    assert_eq!(game.active_seat, 0);
+    assert!(game.players.values().all(|player| !player.current_player));
+    assert_eq!(game.count, 13);
+}
+
+#[test]
+fn test_stand_passes_turn_in_seat_order() {
+    let mut game = BlackjackGame::new(Deck::empty());
+    game.register_update_player(
+        1,
+        Player {
+            seat_id: 1,
+            bet: Amount::from_tokens(10),
+            balance: Amount::from_tokens(100),
+            ..Player::default()
+        },
+    );
+    game.register_update_player(
+        2,
+        Player {
+            seat_id: 2,
+            bet: Amount::from_tokens(10),
+            balance: Amount::from_tokens(100),
+            ..Player::default()
+        },
+    );
+    game.start_round();
+    for (seat_id, card) in [(Some(1), 2), (Some(2), 3), (None, 4), (Some(1), 5), (Some(2), 6), (None, 0)] {
+        game.add_card(seat_id, card);
+    }
+    assert!(game.is_turn_of(1));
+
+    // only the active seat can stand
+    game.stand(2);
+    assert!(game.is_turn_of(1));
+    game.stand(1);
+    assert!(game.is_turn_of(2));
+    game.add_card(Some(2), 7);
+    assert!(game.is_turn_of(2));
+    game.stand(2);
+    assert_eq!(game.status, BlackjackStatus::DealerTurn);
+}
+
+#[test]
+fn test_leaving_active_seat_passes_turn() {
+    let mut game = BlackjackGame::new(Deck::empty());
+    game.register_update_player(
+        1,
+        Player {
+            seat_id: 1,
+            bet: Amount::from_tokens(10),
+            balance: Amount::from_tokens(100),
+            ..Player::default()
+        },
+    );
+    game.register_update_player(
+        2,
+        Player {
+            seat_id: 2,
+            bet: Amount::from_tokens(10),
+            balance: Amount::from_tokens(100),
+            ..Player::default()
+        },
+    );
+    game.start_round();
+    for (seat_id, card) in [(Some(1), 2), (Some(2), 3), (None, 4), (Some(1), 5), (Some(2), 6), (None, 0)] {
+        game.add_card(seat_id, card);
+    }
+
+    assert_eq!(game.release_seat(1), Amount::from_tokens(10));
+    assert!(game.is_turn_of(2));
+    assert_eq!(game.release_seat(2), Amount::from_tokens(10));
+    assert_eq!(game.status, BlackjackStatus::WaitingForPlayer);
+}
+
+#[test]
+fn test_full_round_from_bets_to_settlement() {
+    // Dealt from the end: seats 1 and 2 and the dealer face up, then face down, then hits and the dealer draw
+    let mut table = BlackjackGame::new(Deck::with_cards(vec![9, 8, 5, 10, 7, 6, 6, 13, 10]));
+    let mut mirror = BlackjackGame::new(Deck::empty());
+    for game in [&mut table, &mut mirror] {
+        for seat_id in [1, 2] {
+            game.register_update_player(
+                seat_id,
+                Player {
+                    seat_id,
+                    balance: Amount::from_tokens(100),
+                    ..Player::default()
+                },
+            );
+        }
+        game.place_bet(1, Amount::from_tokens(10));
+        game.place_bet(2, Amount::from_tokens(20));
+        assert_eq!(game.status, BlackjackStatus::WaitingForBets);
+        game.start_round();
+    }
+
+    // The mirror follows the CardDealt events, the dealer hole card stays face down
+    let mut deal_order: Vec<Option<u8>> = table.betting_seats().into_iter().map(Some).collect();
+    deal_order.push(None);
+    for face_up in [true, false] {
+        for seat_id in deal_order.iter().copied() {
+            let card = table.deck.deal_card().unwrap();
+            table.add_card(seat_id, card);
+            mirror.add_card(seat_id, if seat_id.is_none() && !face_up { 0 } else { card });
+        }
+    }
+    assert_eq!(mirror.dealer.hand, vec![6, 0]);
+    assert!(table.is_turn_of(1) && mirror.is_turn_of(1));
+
+    // Seat 1 hits to 21 and the turn moves on, seat 2 hits and busts
+    for seat_id in [1, 2] {
+        let card = table.deck.deal_card().unwrap();
+        table.add_card(Some(seat_id), card);
+        mirror.add_card(Some(seat_id), card);
+    }
+    assert_eq!(table.status, BlackjackStatus::DealerTurn);
+    assert_eq!(mirror.status, BlackjackStatus::DealerTurn);
+
+    // The dealer draws to 25 and busts, a busted seat still loses
+    while RuleVariant::Standard.dealer_draws(&table.dealer) {
+        let card = table.deck.deal_card().unwrap();
+        table.add_card(None, card);
+    }
+    assert_eq!(table.dealer.hand, vec![6, 10, 9]);
+    assert!(table.deck.is_empty());
+    mirror.dealer.hand = table.dealer.hand.clone();
+
+    let results = table.round_settlements();
+    assert_eq!(
+        results,
+        vec![
+            SeatSettlement {
+                seat_id: 1,
+                outcome: GameOutcome::PlayerWins,
+                bet: Amount::from_tokens(10),
+                payout: Amount::from_tokens(20),
+            },
+            SeatSettlement {
+                seat_id: 2,
+                outcome: GameOutcome::DealerWins,
+                bet: Amount::from_tokens(20),
+                payout: Amount::ZERO,
+            },
+        ]
+    );
+    assert_eq!(mirror.round_settlements(), results);
+    table.settle_round(&results);
+    mirror.settle_round(&results);
+    for game in [&table, &mirror] {
+        assert_eq!(game.status, BlackjackStatus::RoundEnded);
+        assert_eq!(game.pot, Amount::ZERO);
+        assert_eq!(game.players[&1].balance, Amount::from_tokens(110));
+        assert_eq!(game.players[&2].balance, Amount::from_tokens(80));
+        assert!(game.players.values().all(|player| player.bet.is_zero() && !player.current_player));
+    }
+
+    // The next bet opens the next round
+    table.place_bet(2, Amount::from_tokens(10));
+    assert_eq!(table.status, BlackjackStatus::WaitingForBets);
+}
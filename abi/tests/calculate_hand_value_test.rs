@@ -1,4 +1,6 @@
-use abi::deck::calculate_hand_value;
+use abi::blackjack::RuleVariant;
+use abi::deck::{calculate_hand_value, is_soft_hand};
+use abi::player_dealer::Dealer;
 
 #[test]
 fn test_empty_hand() {
@@ -199,3 +201,30 @@ fn test_mixed_suits_same_ranks() {
     // Four Kings from different suits = 40
     assert_eq!(calculate_hand_value(&vec![13, 26, 39, 52]), 40);
 }
+
+#[test]
+fn test_soft_hands() {
+    // Ace + 6 = soft 17
+    assert!(is_soft_hand(&[1, 6]));
+    // Ace + 6 + 10 = hard 17, the Ace counts as 1
+    assert!(!is_soft_hand(&[1, 6, 10]));
+    // 10 + 7 = hard 17
+    assert!(!is_soft_hand(&[10, 7]));
+    // Ace + Ace = soft 12
+    assert!(is_soft_hand(&[1, 14]));
+}
+
+#[test]
+fn test_dealer_draws_by_rule_variant() {
+    let soft_17 = Dealer { hand: vec![1, 6] };
+    let hard_17 = Dealer { hand: vec![10, 7] };
+    let sixteen = Dealer { hand: vec![10, 6] };
+
+    assert!(RuleVariant::Standard.dealer_draws(&sixteen));
+    assert!(!RuleVariant::Standard.dealer_draws(&soft_17));
+    assert!(!RuleVariant::Standard.dealer_draws(&hard_17));
+
+    assert!(RuleVariant::DealerHitsSoft17.dealer_draws(&sixteen));
+    assert!(RuleVariant::DealerHitsSoft17.dealer_draws(&soft_17));
+    assert!(!RuleVariant::DealerHitsSoft17.dealer_draws(&hard_17));
+}
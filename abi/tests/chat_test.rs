@@ -0,0 +1,49 @@
+use abi::chat::{
+    is_chat_rate_limited, push_chat_history, sanitize_chat_text, ChatEntry, Emote, CHAT_HISTORY_SIZE, CHAT_RATE_LIMIT_IN_MICROS, MAX_CHAT_MESSAGE_LENGTH,
+};
+use linera_sdk::linera_base_types::{ChainId, Timestamp};
+
+fn entry(sent_at: u64) -> ChatEntry {
+    ChatEntry {
+        sender: format!("{:064x}", 1).parse::<ChainId>().unwrap(),
+        seat_id: Some(1),
+        text: String::new(),
+        emote: Some(Emote::ThumbsUp),
+        sent_at: Timestamp::from(sent_at),
+    }
+}
+
+#[test]
+fn test_sanitize_chat_text() {
+    assert_eq!(sanitize_chat_text("  nice hand!  "), Some("nice hand!".to_string()));
+    assert_eq!(sanitize_chat_text("gg\u{7}\n"), Some("gg".to_string()));
+    assert_eq!(sanitize_chat_text("   "), None);
+    assert_eq!(sanitize_chat_text(""), None);
+}
+
+#[test]
+fn test_sanitize_chat_text_length_counts_characters() {
+    let longest = "é".repeat(MAX_CHAT_MESSAGE_LENGTH);
+    assert_eq!(sanitize_chat_text(&longest), Some(longest.clone()));
+    assert_eq!(sanitize_chat_text(&format!("{}a", longest)), None);
+}
+
+#[test]
+fn test_chat_rate_limit() {
+    assert!(!is_chat_rate_limited(None, Timestamp::from(0)));
+    let last_sent = Some(Timestamp::from(1_000));
+    assert!(is_chat_rate_limited(last_sent, Timestamp::from(1_000)));
+    assert!(is_chat_rate_limited(last_sent, Timestamp::from(999 + CHAT_RATE_LIMIT_IN_MICROS)));
+    assert!(!is_chat_rate_limited(last_sent, Timestamp::from(1_000 + CHAT_RATE_LIMIT_IN_MICROS)));
+}
+
+#[test]
+fn test_chat_history_is_bounded() {
+    let mut history = Vec::new();
+    for sent_at in 0..(CHAT_HISTORY_SIZE as u64 + 5) {
+        push_chat_history(&mut history, entry(sent_at));
+    }
+    assert_eq!(history.len(), CHAT_HISTORY_SIZE);
+    assert_eq!(history[0].sent_at, Timestamp::from(5));
+    assert_eq!(history.last().unwrap().sent_at, Timestamp::from(CHAT_HISTORY_SIZE as u64 + 4));
+}
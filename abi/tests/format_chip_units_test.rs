@@ -0,0 +1,44 @@
+use abi::bet_chip_profile::format_chip_units;
+
+#[test]
+fn test_values_below_one_thousand() {
+    assert_eq!(format_chip_units(0), "0");
+    assert_eq!(format_chip_units(1), "1");
+    assert_eq!(format_chip_units(100), "100");
+    assert_eq!(format_chip_units(999), "999");
+}
+
+#[test]
+fn test_thousand_boundaries() {
+    assert_eq!(format_chip_units(1_000), "1K");
+    assert_eq!(format_chip_units(1_049), "1K");
+    assert_eq!(format_chip_units(1_050), "1.1K");
+    assert_eq!(format_chip_units(1_500), "1.5K");
+    assert_eq!(format_chip_units(999_949), "999.9K");
+}
+
+#[test]
+fn test_rounding_up_to_one_thousand_moves_to_next_suffix() {
+    assert_eq!(format_chip_units(999_950), "1M");
+    assert_eq!(format_chip_units(999_999), "1M");
+    assert_eq!(format_chip_units(999_950_000), "1B");
+    assert_eq!(format_chip_units(999_949_999), "999.9M");
+}
+
+#[test]
+fn test_every_short_scale_suffix_boundary() {
+    let suffixes = ["K", "M", "B", "T", "Q", "Qi", "S", "Sp", "O", "N", "D"];
+    let mut divisor: u128 = 1;
+    for suffix in suffixes {
+        divisor *= 1_000;
+        assert_eq!(format_chip_units(divisor), format!("1{}", suffix));
+        assert_eq!(format_chip_units(divisor * 25 / 10), format!("2.5{}", suffix));
+        assert_eq!(format_chip_units(divisor * 999), format!("999{}", suffix));
+    }
+}
+
+#[test]
+fn test_largest_value_keeps_last_suffix() {
+    assert_eq!(format_chip_units(u128::MAX), "340282.4D");
+    assert_eq!(format_chip_units(300_000 * 10u128.pow(33)), "300000D");
+}
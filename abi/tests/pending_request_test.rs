@@ -0,0 +1,43 @@
+use abi::blackjack::{PendingRequest, MAX_REQUEST_ATTEMPTS};
+use linera_sdk::linera_base_types::Timestamp;
+
+#[test]
+fn test_pending_request_expires_after_deadline() {
+    let request = PendingRequest::new(7, 1_000, 500);
+    assert_eq!(request.request_id, 7);
+    assert_eq!(request.deadline, Timestamp::from(1_500));
+    assert!(!request.is_expired(Timestamp::from(1_000)));
+    assert!(!request.is_expired(Timestamp::from(1_500)));
+    assert!(request.is_expired(Timestamp::from(1_501)));
+}
+
+#[test]
+fn test_pending_request_deadline_saturates() {
+    let request = PendingRequest::new(1, u64::MAX - 1, 500);
+    assert_eq!(request.deadline, Timestamp::from(u64::MAX));
+    assert!(!request.is_expired(Timestamp::from(u64::MAX)));
+}
+
+#[test]
+fn test_pending_request_resend_keeps_request_id() {
+    let mut request = PendingRequest::new(7, 1_000, 500);
+    assert_eq!(request.attempts, 1);
+    assert!(request.can_resend());
+
+    request.resend(2_000, 500);
+    assert_eq!(request.request_id, 7);
+    assert_eq!(request.attempts, 2);
+    assert_eq!(request.deadline, Timestamp::from(2_500));
+    assert!(!request.is_expired(Timestamp::from(2_000)));
+}
+
+#[test]
+fn test_pending_request_attempts_are_bounded() {
+    let mut request = PendingRequest::new(7, 1_000, 500);
+    for _ in 1..MAX_REQUEST_ATTEMPTS {
+        assert!(request.can_resend());
+        request.resend(1_000, 500);
+    }
+    assert_eq!(request.attempts, MAX_REQUEST_ATTEMPTS);
+    assert!(!request.can_resend());
+}
@@ -0,0 +1,60 @@
+use abi::blackjack::{hash_invite_code, validate_invite_code, PrivateTable, MIN_INVITE_CODE_LENGTH, PRIVATE_TABLE_IDLE_DURATION_IN_MICROS};
+use linera_sdk::linera_base_types::{ChainId, Timestamp};
+
+fn chain(n: u8) -> ChainId {
+    format!("{:064x}", n).parse().unwrap()
+}
+
+fn private_table(allowlist: Vec<ChainId>) -> PrivateTable {
+    PrivateTable {
+        owner: chain(9),
+        invite_code_hash: hash_invite_code(chain(1), "friday-night"),
+        allowlist,
+    }
+}
+
+#[test]
+fn test_invite_code_hash_is_salted_per_table() {
+    assert_eq!(hash_invite_code(chain(1), "friday-night"), hash_invite_code(chain(1), "friday-night"));
+    assert_ne!(hash_invite_code(chain(1), "friday-night"), hash_invite_code(chain(2), "friday-night"));
+    assert_ne!(hash_invite_code(chain(1), "friday-night"), hash_invite_code(chain(1), "saturday-night"));
+}
+
+#[test]
+fn test_invite_code_minimum_length() {
+    assert!(validate_invite_code("friday-night").is_ok());
+    assert!(validate_invite_code(&"x".repeat(MIN_INVITE_CODE_LENGTH)).is_ok());
+    assert!(validate_invite_code(&"x".repeat(MIN_INVITE_CODE_LENGTH - 1)).is_err());
+    // surrounding spaces do not count
+    assert!(validate_invite_code("   1234   ").is_err());
+    assert!(validate_invite_code("").is_err());
+}
+
+#[test]
+fn test_private_table_checks_code_for_its_own_chain() {
+    let table = private_table(vec![]);
+    assert!(table.is_valid_code(chain(1), "friday-night"));
+    assert!(!table.is_valid_code(chain(1), "Friday-Night"));
+    assert!(!table.is_valid_code(chain(2), "friday-night"));
+}
+
+#[test]
+fn test_private_table_allowlist() {
+    assert!(private_table(vec![]).is_allowed(chain(5)));
+    let table = private_table(vec![chain(3), chain(4)]);
+    assert!(table.is_allowed(chain(3)));
+    assert!(!table.is_allowed(chain(5)));
+}
+
+#[test]
+fn test_private_table_goes_idle() {
+    let active_at = Timestamp::from(1_000);
+    assert!(!PrivateTable::is_idle(active_at, Timestamp::from(1_000)));
+    assert!(!PrivateTable::is_idle(
+        active_at,
+        Timestamp::from(1_000 + PRIVATE_TABLE_IDLE_DURATION_IN_MICROS)
+    ));
+    assert!(PrivateTable::is_idle(active_at, Timestamp::from(1_001 + PRIVATE_TABLE_IDLE_DURATION_IN_MICROS)));
+    // activity recorded after the heartbeat time never counts as idle
+    assert!(!PrivateTable::is_idle(active_at, Timestamp::from(0)));
+}
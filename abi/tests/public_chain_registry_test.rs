@@ -0,0 +1,24 @@
+use abi::blackjack::check_public_chain_retirement;
+use linera_sdk::linera_base_types::ChainId;
+
+#[test]
+fn test_last_live_public_chain_is_kept() {
+    let last = format!("{:064x}", 1).parse::<ChainId>().unwrap();
+    assert!(check_public_chain_retirement(&[last], last).is_err());
+}
+
+#[test]
+fn test_public_chain_retires_while_another_is_live() {
+    let first = format!("{:064x}", 1).parse::<ChainId>().unwrap();
+    let second = format!("{:064x}", 2).parse::<ChainId>().unwrap();
+    assert_eq!(check_public_chain_retirement(&[first, second], first), Ok(()));
+}
+
+#[test]
+fn test_draining_public_chain_retires_next_to_last_live_one() {
+    // Removing a chain that already drains leaves the live set untouched
+    let live = format!("{:064x}", 1).parse::<ChainId>().unwrap();
+    let draining = format!("{:064x}", 2).parse::<ChainId>().unwrap();
+    assert_eq!(check_public_chain_retirement(&[live], draining), Ok(()));
+    assert_eq!(check_public_chain_retirement(&[], draining), Ok(()));
+}
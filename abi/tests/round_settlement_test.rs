@@ -0,0 +1,74 @@
+use abi::blackjack::{seat_outcome, BlackjackGame, BlackjackStatus, GameOutcome, RoundSettlement};
+use abi::deck::Deck;
+use abi::player_dealer::Player;
+use linera_sdk::linera_base_types::{Amount, ChainId};
+
+fn chain(n: u8) -> ChainId {
+    format!("{:064x}", n).parse().unwrap()
+}
+
+fn player(seat_id: u8, bet: u128, hand: Vec<u8>) -> Player {
+    Player {
+        seat_id,
+        bet: Amount::from_tokens(bet),
+        balance: Amount::from_tokens(1_000),
+        hand,
+        chain_id: Some(chain(seat_id)),
+        ..Player::default()
+    }
+}
+
+fn settled_game() -> BlackjackGame {
+    let mut game = BlackjackGame::new(Deck::empty());
+    game.register_update_player(3, player(3, 20, vec![10, 7]));
+    game.register_update_player(1, player(1, 50, vec![10, 13]));
+    game.register_update_player(2, player(2, 30, vec![10, 13, 5]));
+    game.dealer.hand = vec![10, 7];
+    game.update_status(BlackjackStatus::DealerTurn);
+    game
+}
+
+#[test]
+fn test_seat_outcome_against_dealer() {
+    assert_eq!(seat_outcome(20, 17), GameOutcome::PlayerWins);
+    assert_eq!(seat_outcome(17, 20), GameOutcome::DealerWins);
+    assert_eq!(seat_outcome(17, 17), GameOutcome::Draw);
+    assert_eq!(seat_outcome(12, 26), GameOutcome::PlayerWins);
+    // A busted player loses even when the dealer busts too
+    assert_eq!(seat_outcome(25, 26), GameOutcome::DealerWins);
+}
+
+#[test]
+fn test_round_settlements_are_ordered_by_seat() {
+    let results = settled_game().round_settlements();
+    let seats: Vec<u8> = results.iter().map(|result| result.seat_id).collect();
+    assert_eq!(seats, vec![1, 2, 3]);
+
+    assert_eq!(results[0].outcome, GameOutcome::PlayerWins);
+    assert_eq!(results[0].payout, Amount::from_tokens(100));
+    assert_eq!(results[1].outcome, GameOutcome::DealerWins);
+    assert_eq!(results[1].payout, Amount::ZERO);
+    assert_eq!(results[2].outcome, GameOutcome::Draw);
+    assert_eq!(results[2].payout, Amount::from_tokens(20));
+}
+
+#[test]
+fn test_round_settlements_skip_seats_without_bet() {
+    let mut game = settled_game();
+    game.place_bet(2, Amount::ZERO);
+    let results = game.round_settlements();
+    assert!(results.iter().all(|result| result.seat_id != 2));
+}
+
+#[test]
+fn test_round_settlement_results_per_seat() {
+    let settlement = RoundSettlement {
+        play_chain_id: chain(9),
+        round: 12,
+        public_chain: Some(chain(8)),
+        results: settled_game().round_settlements(),
+    };
+    assert_eq!(settlement.result_for(1).map(|result| result.bet), Some(Amount::from_tokens(50)));
+    assert_eq!(settlement.result_for(2).map(|result| result.bet), Some(Amount::from_tokens(30)));
+    assert!(settlement.result_for(4).is_none());
+}
@@ -0,0 +1,52 @@
+use abi::schema::{Envelope, SCHEMA_VERSION};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+enum MessageV1 {
+    Ping { seat_id: u8 },
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+enum MessageV2 {
+    Ping { seat_id: u8 },
+    Pong,
+}
+
+#[test]
+fn test_envelope_round_trip() {
+    let envelope = Envelope::seal(&MessageV1::Ping { seat_id: 2 });
+    assert_eq!(envelope.version, SCHEMA_VERSION);
+    assert_eq!(envelope.open::<MessageV1>(), Some(MessageV1::Ping { seat_id: 2 }));
+}
+
+#[test]
+fn test_newer_known_variant_still_decodes() {
+    let mut envelope = Envelope::seal(&MessageV2::Ping { seat_id: 3 });
+    envelope.version = SCHEMA_VERSION + 1;
+    assert_eq!(envelope.open::<MessageV1>(), Some(MessageV1::Ping { seat_id: 3 }));
+}
+
+#[test]
+fn test_newer_unknown_variant_is_skipped() {
+    let mut envelope = Envelope::seal(&MessageV2::Pong);
+    envelope.version = SCHEMA_VERSION + 1;
+    assert_eq!(envelope.open::<MessageV1>(), None);
+}
+
+#[test]
+fn test_older_changed_payload_is_skipped() {
+    let envelope = Envelope {
+        version: SCHEMA_VERSION - 1,
+        payload: vec![9, 9, 9],
+    };
+    assert_eq!(envelope.open::<MessageV1>(), None);
+}
+
+#[test]
+fn test_current_schema_garbage_is_skipped() {
+    let envelope = Envelope {
+        version: SCHEMA_VERSION,
+        payload: vec![9, 9, 9],
+    };
+    assert_eq!(envelope.open::<MessageV1>(), None);
+}
@@ -0,0 +1,150 @@
+use abi::bet_chip_profile::TableStakes;
+use abi::blackjack::{
+    select_play_chain, PlayChainHealth, PlayChainHeartbeat, PlayChainMode, RuleVariant, TableFilter, TableListing, PLAY_CHAIN_STALE_DURATION_IN_MICROS,
+};
+use linera_sdk::linera_base_types::{Amount, ChainId, Timestamp};
+
+fn chain(n: u8) -> ChainId {
+    format!("{:064x}", n).parse().unwrap()
+}
+
+fn listing(min_bet: Option<u128>, last_assigned: u64) -> TableListing {
+    TableListing {
+        stakes: min_bet.map(|min_bet| TableStakes {
+            min_bet: Amount::from_tokens(min_bet),
+            max_bet: Amount::from_tokens(min_bet * 50),
+            chip_base: Amount::from_tokens(min_bet),
+            chip_set: None,
+        }),
+        rules: RuleVariant::Standard,
+        mode: PlayChainMode::Open,
+        last_assigned,
+    }
+}
+
+fn stake_range(min: Option<u128>, max: Option<u128>) -> TableFilter {
+    TableFilter {
+        min_stake: min.map(Amount::from_tokens),
+        max_stake: max.map(Amount::from_tokens),
+        ..TableFilter::default()
+    }
+}
+
+#[test]
+fn test_empty_filter_matches_every_table() {
+    let filter = TableFilter::default();
+    assert!(filter.matches(chain(1), &listing(None, 0)));
+    assert!(filter.matches(chain(1), &listing(Some(10), 0)));
+}
+
+#[test]
+fn test_stake_range_filter() {
+    let filter = stake_range(Some(10), Some(100));
+    assert!(filter.matches(chain(1), &listing(Some(10), 0)));
+    assert!(filter.matches(chain(1), &listing(Some(100), 0)));
+    assert!(!filter.matches(chain(1), &listing(Some(5), 0)));
+    assert!(!filter.matches(chain(1), &listing(Some(500), 0)));
+    // tables without stakes never match a stake range
+    assert!(!filter.matches(chain(1), &listing(None, 0)));
+}
+
+#[test]
+fn test_friend_table_filter() {
+    let filter = TableFilter {
+        friend_table: Some(chain(2)),
+        ..TableFilter::default()
+    };
+    assert!(!filter.matches(chain(1), &listing(None, 0)));
+    assert!(filter.matches(chain(2), &listing(None, 0)));
+}
+
+#[test]
+fn test_rule_variant_filter() {
+    let filter = TableFilter {
+        rule_variant: Some(RuleVariant::DealerHitsSoft17),
+        ..TableFilter::default()
+    };
+    assert!(!filter.matches(chain(1), &listing(None, 0)));
+    let soft_17_listing = TableListing {
+        rules: RuleVariant::DealerHitsSoft17,
+        ..listing(None, 0)
+    };
+    assert!(filter.matches(chain(1), &soft_17_listing));
+}
+
+#[test]
+fn test_select_least_recently_assigned() {
+    let candidates = vec![
+        (chain(1), 2, listing(None, 5)),
+        (chain(2), 0, listing(None, 3)),
+        (chain(3), 1, listing(None, 4)),
+    ];
+    assert_eq!(select_play_chain(&TableFilter::default(), &candidates), Some(chain(2)));
+}
+
+#[test]
+fn test_select_round_robin_when_never_assigned() {
+    let mut candidates = vec![(chain(1), 0, listing(None, 0)), (chain(2), 0, listing(None, 0))];
+    assert_eq!(select_play_chain(&TableFilter::default(), &candidates), Some(chain(1)));
+    candidates[0].2.last_assigned = 1;
+    assert_eq!(select_play_chain(&TableFilter::default(), &candidates), Some(chain(2)));
+    candidates[1].2.last_assigned = 2;
+    assert_eq!(select_play_chain(&TableFilter::default(), &candidates), Some(chain(1)));
+}
+
+#[test]
+fn test_select_prefers_seat_count_over_assignment_order() {
+    let filter = TableFilter {
+        seat_count: Some(2),
+        ..TableFilter::default()
+    };
+    let candidates = vec![(chain(1), 0, listing(None, 1)), (chain(2), 2, listing(None, 9))];
+    assert_eq!(select_play_chain(&filter, &candidates), Some(chain(2)));
+}
+
+#[test]
+fn test_select_skips_non_matching_tables() {
+    let candidates = vec![(chain(1), 0, listing(Some(500), 0)), (chain(2), 0, listing(Some(10), 7))];
+    assert_eq!(select_play_chain(&stake_range(None, Some(100)), &candidates), Some(chain(2)));
+    assert_eq!(select_play_chain(&stake_range(Some(1_000), None), &candidates), None);
+}
+
+#[test]
+fn test_select_skips_paused_and_draining_tables() {
+    let mut candidates = vec![(chain(1), 0, listing(None, 0)), (chain(2), 0, listing(None, 5))];
+    candidates[0].2.mode = PlayChainMode::Paused;
+    assert_eq!(select_play_chain(&TableFilter::default(), &candidates), Some(chain(2)));
+    candidates[1].2.mode = PlayChainMode::Draining;
+    assert_eq!(select_play_chain(&TableFilter::default(), &candidates), None);
+}
+
+#[test]
+fn test_select_skips_private_tables_even_for_friends() {
+    let mut candidates = vec![(chain(1), 0, listing(None, 0))];
+    candidates[0].2.mode = PlayChainMode::Private;
+    let filter = TableFilter {
+        friend_table: Some(chain(1)),
+        ..TableFilter::default()
+    };
+    assert_eq!(select_play_chain(&filter, &candidates), None);
+}
+
+#[test]
+fn test_play_chain_health_goes_stale() {
+    let heartbeat = PlayChainHeartbeat {
+        player_number: 1,
+        ..PlayChainHeartbeat::default()
+    };
+    let health = PlayChainHealth::new(chain(1), heartbeat, Timestamp::from(1_000));
+    assert!(!health.is_stale(Timestamp::from(1_000)));
+    assert!(!health.is_stale(Timestamp::from(1_000 + PLAY_CHAIN_STALE_DURATION_IN_MICROS)));
+    assert!(health.is_stale(Timestamp::from(1_001 + PLAY_CHAIN_STALE_DURATION_IN_MICROS)));
+    // a heartbeat from the future never counts as stale
+    assert!(!health.is_stale(Timestamp::from(0)));
+}
+
+#[test]
+fn test_empty_play_chain_never_goes_stale() {
+    let health = PlayChainHealth::new(chain(1), PlayChainHeartbeat::default(), Timestamp::from(1_000));
+    assert!(!health.is_stale(Timestamp::from(1_001 + 10 * PLAY_CHAIN_STALE_DURATION_IN_MICROS)));
+}
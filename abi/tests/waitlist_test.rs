@@ -0,0 +1,43 @@
+use abi::blackjack::{next_waitlist_match, SeatOffer, WaitlistEntry, SEAT_OFFER_DURATION_IN_MICROS};
+use linera_sdk::linera_base_types::{ChainId, Timestamp};
+
+fn chain(n: u8) -> ChainId {
+    format!("{:064x}", n).parse().unwrap()
+}
+
+fn entry(n: u8, seat_id: Option<u8>) -> WaitlistEntry {
+    WaitlistEntry {
+        chain_id: chain(n),
+        seat_id,
+        joined_at: Timestamp::from(n as u64),
+    }
+}
+
+#[test]
+fn test_seat_offer_expires_after_deadline() {
+    let offer = SeatOffer::new(chain(1), 2, 1_000);
+    assert_eq!(offer.deadline, Timestamp::from(1_000 + SEAT_OFFER_DURATION_IN_MICROS));
+    assert!(!offer.is_expired(Timestamp::from(1_000 + SEAT_OFFER_DURATION_IN_MICROS)));
+    assert!(offer.is_expired(Timestamp::from(1_001 + SEAT_OFFER_DURATION_IN_MICROS)));
+}
+
+#[test]
+fn test_waitlist_match_is_first_come_first_served() {
+    let waitlist = vec![entry(1, None), entry(2, None)];
+    assert_eq!(next_waitlist_match(&waitlist, 3, &[]), Some(0));
+}
+
+#[test]
+fn test_waitlist_match_respects_wanted_seat() {
+    let waitlist = vec![entry(1, Some(1)), entry(2, Some(3)), entry(3, None)];
+    assert_eq!(next_waitlist_match(&waitlist, 3, &[]), Some(1));
+    assert_eq!(next_waitlist_match(&waitlist, 2, &[]), Some(2));
+    assert_eq!(next_waitlist_match(&waitlist[..2], 2, &[]), None);
+}
+
+#[test]
+fn test_waitlist_match_skips_chains_holding_an_offer() {
+    let waitlist = vec![entry(1, None), entry(2, None)];
+    assert_eq!(next_waitlist_match(&waitlist, 1, &[chain(1)]), Some(1));
+    assert_eq!(next_waitlist_match(&waitlist, 1, &[chain(1), chain(2)]), None);
+}
@@ -3,8 +3,11 @@
 mod state;
 
 use self::state::BankrollState;
-use bankroll::{BankrollMessage, BankrollOperation, BankrollParameters, BankrollResponse, DebtRecord, DebtStatus, PublicChainBalances, TokenPotRecord};
-use linera_sdk::linera_base_types::ChainId;
+use bankroll::{
+    BankrollMessage, BankrollOperation, BankrollParameters, BankrollResponse, BonusSchedule, DebtRecord, DebtStatus, PublicChainBalances, ResponsibleGaming,
+    TokenPotRecord, UserChainReport,
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ChainId};
 use linera_sdk::{
     linera_base_types::WithContractAbi,
     views::{RootView, View},
@@ -45,18 +48,9 @@ impl Contract for BankrollContract {
                 log::info!("\n\nBankrollOperation::Balance");
                 log::info!("BankrollOperation::Balance request from  {:?}", owner);
 
+                // Read only, bonuses including the first one are claimed through BankrollOperation::ClaimBonus
                 let balance_async = self.state.accounts.get(&owner).await;
-                let mut balance = balance_async.expect("unable to get balance").unwrap_or_default();
-
-                let daily_bonus = self.state.daily_bonus.get_mut();
-                if daily_bonus.is_zero() {
-                    daily_bonus.update_bonus(self.runtime.application_parameters().bonus);
-                }
-                balance.saturating_add_assign(daily_bonus.claim_bonus(self.runtime.system_time()));
-
-                self.state.accounts.insert(&owner, balance).unwrap_or_else(|_| {
-                    panic!("unable to update {:?} balance", owner);
-                });
+                let balance = balance_async.expect("unable to get balance").unwrap_or_default();
 
                 log::info!("BankrollOperation::Balance returning balance: {} for owner: {:?}", balance, owner);
                 BankrollResponse::Balance(balance)
@@ -65,13 +59,197 @@ impl Contract for BankrollContract {
                 log::info!("\n\nBankrollOperation::UpdateBalance");
                 log::info!("BankrollOperation::UpdateBalance request from {:?}, updating balance to: {}", owner, amount);
 
+                // Track net losses for responsible-gaming limits
+                let previous_balance = self.state.accounts.get(&owner).await.expect("unable to get balance").unwrap_or_default();
+                let current_time = self.runtime.system_time();
+                let mut gaming = self.get_responsible_gaming(&owner).await;
+                gaming.record_balance_change(previous_balance, amount, current_time);
+                self.save_responsible_gaming(&owner, gaming);
+
                 self.state.accounts.insert(&owner, amount).unwrap_or_else(|_| {
                     panic!("unable to update {:?} balance", owner);
                 });
+                self.adjust_player_balances(previous_balance, amount);
+                self.report_user_chain();
 
                 log::info!("BankrollOperation::UpdateBalance completed for owner: {:?}, new balance: {}", owner, amount);
                 BankrollResponse::Ok
             }
+            BankrollOperation::ClaimBonus { owner } => {
+                log::info!("\n\nBankrollOperation::ClaimBonus");
+                log::info!("BankrollOperation::ClaimBonus request from {:?}", owner);
+
+                let balance_async = self.state.accounts.get(&owner).await;
+                let mut balance = balance_async.expect("unable to get balance").unwrap_or_default();
+
+                // The first claim also registers this chain with the Master Chain, so bonus schedule updates reach it
+                self.report_user_chain();
+                let bonus = self.claim_daily_bonus(balance);
+                if bonus.is_zero() {
+                    log::info!("No bonus available for owner: {:?}", owner);
+                    return BankrollResponse::Balance(balance);
+                }
+                balance.saturating_add_assign(bonus);
+
+                self.state.accounts.insert(&owner, balance).unwrap_or_else(|_| {
+                    panic!("unable to update {:?} balance", owner);
+                });
+                self.adjust_player_balances(Amount::ZERO, bonus);
+                self.report_user_chain();
+
+                log::info!(
+                    "BankrollOperation::ClaimBonus credited {} to owner: {:?}, new balance: {}",
+                    bonus,
+                    owner,
+                    balance
+                );
+                BankrollResponse::Balance(balance)
+            }
+            BankrollOperation::SetGamingLimits { owner, limits } => {
+                log::info!("\n\nBankrollOperation::SetGamingLimits");
+                log::info!("BankrollOperation::SetGamingLimits request from {:?}, limits: {:?}", owner, limits);
+
+                let current_time = self.runtime.system_time();
+                let mut gaming = self.get_responsible_gaming(&owner).await;
+                gaming.refresh(current_time);
+                gaming.update_limits(limits, current_time);
+
+                log::info!(
+                    "Gaming limits for {:?} - active: {:?}, pending: {:?} effective at {:?}",
+                    owner,
+                    gaming.limits,
+                    gaming.pending_limits,
+                    gaming.pending_effective_at
+                );
+                self.save_responsible_gaming(&owner, gaming);
+                BankrollResponse::Ok
+            }
+            BankrollOperation::SelfExclude { owner, duration_micros } => {
+                log::info!("\n\nBankrollOperation::SelfExclude");
+                log::info!("BankrollOperation::SelfExclude request from {:?}, duration: {}", owner, duration_micros);
+
+                let current_time = self.runtime.system_time();
+                let mut gaming = self.get_responsible_gaming(&owner).await;
+                gaming.self_exclude(current_time, duration_micros);
+
+                log::info!("Owner {:?} self-excluded until {:?}", owner, gaming.self_excluded_until);
+                self.save_responsible_gaming(&owner, gaming);
+                BankrollResponse::Ok
+            }
+            BankrollOperation::CheckBet { owner, amount } => {
+                log::info!("\n\nBankrollOperation::CheckBet");
+                log::info!("BankrollOperation::CheckBet request from {:?}, amount: {}", owner, amount);
+
+                let current_time = self.runtime.system_time();
+                let mut gaming = self.get_responsible_gaming(&owner).await;
+                let result = gaming.check_bet(amount, current_time);
+                self.save_responsible_gaming(&owner, gaming);
+
+                match result {
+                    Ok(()) => BankrollResponse::Ok,
+                    Err(reason) => {
+                        log::info!("Bet of {} refused for {:?}: {}", amount, owner, reason);
+                        BankrollResponse::BetRefused(reason)
+                    }
+                }
+            }
+            BankrollOperation::EndSession { owner } => {
+                log::info!("\n\nBankrollOperation::EndSession");
+                log::info!("BankrollOperation::EndSession request from {:?}", owner);
+
+                let current_time = self.runtime.system_time();
+                let mut gaming = self.get_responsible_gaming(&owner).await;
+                gaming.end_session(current_time);
+                self.save_responsible_gaming(&owner, gaming);
+                BankrollResponse::Ok
+            }
+            BankrollOperation::EscrowBuyIn { owner, amount } => {
+                log::info!("\n\nBankrollOperation::EscrowBuyIn");
+                self.assert_application_caller("BankrollOperation::EscrowBuyIn");
+                log::info!("BankrollOperation::EscrowBuyIn request from {:?}, amount: {}", owner, amount);
+
+                let balance = self.state.accounts.get(&owner).await.expect("unable to get balance").unwrap_or_default();
+                if amount > balance {
+                    log::info!("Buy-in of {} refused for {:?}, balance is {}", amount, owner, balance);
+                    return BankrollResponse::BetRefused(format!("buy-in of {} exceeds the balance of {}", amount, balance));
+                }
+                let current_time = self.runtime.system_time();
+                let mut gaming = self.get_responsible_gaming(&owner).await;
+                let result = gaming.check_buy_in(current_time);
+                self.save_responsible_gaming(&owner, gaming);
+                if let Err(reason) = result {
+                    log::info!("Buy-in of {} refused for {:?}: {}", amount, owner, reason);
+                    return BankrollResponse::BetRefused(reason);
+                }
+
+                // Moving tokens into escrow is neither a win nor a loss for the gaming limits
+                let mut escrow = self.state.escrows.get(&owner).await.expect("unable to get escrow").unwrap_or_default();
+                escrow.saturating_add_assign(amount);
+                self.state.accounts.insert(&owner, balance.saturating_sub(amount)).unwrap_or_else(|_| {
+                    panic!("unable to update {:?} balance", owner);
+                });
+                self.state.escrows.insert(&owner, escrow).unwrap_or_else(|_| {
+                    panic!("unable to update {:?} escrow", owner);
+                });
+
+                log::info!("BankrollOperation::EscrowBuyIn completed for owner: {:?}, escrow: {}", owner, escrow);
+                BankrollResponse::Escrow(escrow)
+            }
+            BankrollOperation::ReleaseEscrow { owner } => {
+                log::info!("\n\nBankrollOperation::ReleaseEscrow");
+                self.assert_application_caller("BankrollOperation::ReleaseEscrow");
+                log::info!("BankrollOperation::ReleaseEscrow request from {:?}", owner);
+
+                let escrow = self.state.escrows.get(&owner).await.expect("unable to get escrow").unwrap_or_default();
+                let mut balance = self.state.accounts.get(&owner).await.expect("unable to get balance").unwrap_or_default();
+                balance.saturating_add_assign(escrow);
+                self.state.escrows.remove(&owner).unwrap_or_else(|_| {
+                    panic!("unable to release {:?} escrow", owner);
+                });
+                self.state.accounts.insert(&owner, balance).unwrap_or_else(|_| {
+                    panic!("unable to update {:?} balance", owner);
+                });
+
+                log::info!(
+                    "BankrollOperation::ReleaseEscrow returned {} to owner: {:?}, new balance: {}",
+                    escrow,
+                    owner,
+                    balance
+                );
+                BankrollResponse::Balance(balance)
+            }
+            BankrollOperation::SettleEscrow { owner, amount } => {
+                log::info!("\n\nBankrollOperation::SettleEscrow");
+                self.assert_application_caller("BankrollOperation::SettleEscrow");
+                log::info!("BankrollOperation::SettleEscrow request from {:?}, updating escrow to: {}", owner, amount);
+
+                // A settled round is a real win or loss for the gaming limits
+                let previous_escrow = self.state.escrows.get(&owner).await.expect("unable to get escrow").unwrap_or_default();
+                let current_time = self.runtime.system_time();
+                let mut gaming = self.get_responsible_gaming(&owner).await;
+                gaming.record_balance_change(previous_escrow, amount, current_time);
+                self.save_responsible_gaming(&owner, gaming);
+
+                self.state.escrows.insert(&owner, amount).unwrap_or_else(|_| {
+                    panic!("unable to update {:?} escrow", owner);
+                });
+                self.adjust_player_balances(previous_escrow, amount);
+                self.report_user_chain();
+
+                log::info!(
+                    "BankrollOperation::SettleEscrow completed for owner: {:?}, escrow: {} -> {}",
+                    owner,
+                    previous_escrow,
+                    amount
+                );
+                BankrollResponse::Escrow(amount)
+            }
+            BankrollOperation::Escrow { owner } => {
+                log::info!("\n\nBankrollOperation::Escrow");
+                let escrow = self.state.escrows.get(&owner).await.expect("unable to get escrow").unwrap_or_default();
+                log::info!("BankrollOperation::Escrow returning escrow: {} for owner: {:?}", escrow, owner);
+                BankrollResponse::Escrow(escrow)
+            }
             BankrollOperation::NotifyDebt { amount, target_chain } => {
                 log::info!("\n\nBankrollOperation::NotifyDebt");
                 log::info!(
@@ -89,8 +267,12 @@ impl Contract for BankrollContract {
                 let debt_record = DebtRecord {
                     id: debt_id,
                     user_chain,
+                    target_chain,
                     amount,
+                    paid_amount: Amount::ZERO,
+                    attempts: 1,
                     created_at,
+                    sent_at: created_at,
                     paid_at: None,
                     status: DebtStatus::Pending,
                 };
@@ -100,11 +282,72 @@ impl Contract for BankrollContract {
                 });
 
                 log::info!("Created debt record: {:?}", debt_record);
+                self.adjust_outstanding_debt(Amount::ZERO, amount);
 
                 self.message_manager(target_chain, BankrollMessage::DebtNotif { debt_id, amount, created_at });
                 log::info!("Sent DebtNotif message to target_chain: {:?}, debt_id: {}", target_chain, debt_id);
+                self.report_user_chain();
                 BankrollResponse::Ok
             }
+            BankrollOperation::RetryDebt { debt_id, target_chain } => {
+                log::info!("\n\nBankrollOperation::RetryDebt");
+                log::info!("BankrollOperation::RetryDebt debt_id: {}, target_chain: {:?}", debt_id, target_chain);
+
+                let mut debt_record = self
+                    .state
+                    .debt_log
+                    .get(&debt_id)
+                    .await
+                    .expect("Failed to get debt record")
+                    .expect("Debt record not found");
+
+                if debt_record.is_settled() {
+                    panic!("Debt {} is already settled with status {:?}", debt_id, debt_record.status);
+                }
+
+                // Expired debts are closed instead of being sent again, they stay outstanding
+                let current_time = self.runtime.system_time();
+                if debt_record.is_expired(current_time) {
+                    debt_record.status = DebtStatus::Expired;
+                    self.state.debt_log.insert(&debt_id, debt_record).unwrap_or_else(|_| {
+                        panic!("Failed to update debt record for debt_id: {}", debt_id);
+                    });
+                    log::info!("Debt {} expired, no further retry", debt_id);
+                    self.report_user_chain();
+                    return BankrollResponse::Ok;
+                }
+
+                if !debt_record.is_retryable(current_time) {
+                    panic!("Debt {} with status {:?} can't be retried", debt_id, debt_record.status);
+                }
+
+                // A Pending debt may still be paid by the chain it was sent to, so it is only sent there again
+                let target_chain = if debt_record.can_reroute() { target_chain } else { debt_record.target_chain };
+                let amount = debt_record.outstanding();
+                debt_record.target_chain = target_chain;
+                debt_record.attempts = debt_record.attempts.saturating_add(1);
+                debt_record.sent_at = current_time;
+                debt_record.status = DebtStatus::Pending;
+                let created_at = debt_record.created_at;
+
+                self.state.debt_log.insert(&debt_id, debt_record).unwrap_or_else(|_| {
+                    panic!("Failed to update debt record for debt_id: {}", debt_id);
+                });
+
+                self.message_manager(target_chain, BankrollMessage::DebtNotif { debt_id, amount, created_at });
+                log::info!(
+                    "Resent DebtNotif to target_chain: {:?}, debt_id: {}, outstanding: {}",
+                    target_chain,
+                    debt_id,
+                    amount
+                );
+                BankrollResponse::Ok
+            }
+            BankrollOperation::Debt { debt_id } => {
+                log::info!("\n\nBankrollOperation::Debt debt_id: {}", debt_id);
+                let debt_record = self.state.debt_log.get(&debt_id).await.expect("Failed to get debt record");
+                BankrollResponse::Debt(debt_record)
+            }
             BankrollOperation::TransferTokenPot { amount, target_chain } => {
                 log::info!("\n\nBankrollOperation::TransferTokenPot");
                 log::info!(
@@ -132,14 +375,100 @@ impl Contract for BankrollContract {
                     amount,
                     chain_id
                 );
+
+                // Enforce the supply cap on minted minus burned tokens
+                let total_minted = self.state.total_minted.get().saturating_add(amount);
+                if let Some(max_supply) = self.runtime.application_parameters().max_supply {
+                    let net_supply = total_minted.saturating_sub(*self.state.total_burned.get());
+                    assert!(
+                        net_supply <= max_supply,
+                        "Minting {} exceeds max supply. Supply after mint: {}, Max: {}",
+                        amount,
+                        net_supply,
+                        max_supply
+                    );
+                }
+                self.state.total_minted.set(total_minted);
+
                 self.message_manager(chain_id, BankrollMessage::TokenIssued { amount });
                 log::info!("Sent TokenIssued message to chain: {:?}, amount: {}", chain_id, amount);
 
-                let data = PublicChainBalances { chain: chain_id, amount };
+                let current_amount = self
+                    .state
+                    .balances
+                    .get(&chain_id)
+                    .await
+                    .expect("Failed to get balances")
+                    .map_or(Amount::ZERO, |balance| balance.amount);
+                let data = PublicChainBalances {
+                    chain: chain_id,
+                    amount: current_amount.saturating_add(amount),
+                };
                 self.state.balances.insert(&chain_id, data).unwrap_or_else(|_| {
                     panic!("Failed to update record for Public Chain ID: {}", chain_id);
                 });
 
+                log::info!("Total minted supply: {}", total_minted);
+                BankrollResponse::Ok
+            }
+            BankrollOperation::RebalanceLiquidity { from_chain, to_chain, amount } => {
+                log::info!("\n\nBankrollOperation::RebalanceLiquidity");
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BankrollOperation::RebalanceLiquidity"
+                );
+                assert_ne!(from_chain, to_chain, "Unable to rebalance liquidity into the same Public Chain");
+                log::info!(
+                    "BankrollOperation::RebalanceLiquidity request from {:?}, moving {} from {:?} to {:?}",
+                    self.runtime.authenticated_signer(),
+                    amount,
+                    from_chain,
+                    to_chain
+                );
+                if let Some(in_flight) = self.rebalance_in_flight(to_chain).await {
+                    panic!("Rebalance of {} to {:?} still in flight", in_flight, to_chain);
+                }
+                self.send_rebalance(from_chain, to_chain, amount).await;
+                log::info!("Sent TransferLiquidity message to chain: {:?}", from_chain);
+                BankrollResponse::Ok
+            }
+            BankrollOperation::Burn { chain_id, amount } => {
+                log::info!("\n\nBankrollOperation::Burn");
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BankrollOperation::Burn"
+                );
+                log::info!(
+                    "BankrollOperation::Burn request from {:?}, burning {} tokens from chain: {:?}",
+                    self.runtime.authenticated_signer(),
+                    amount,
+                    chain_id
+                );
+                self.message_manager(chain_id, BankrollMessage::TokenBurn { amount });
+                log::info!("Sent TokenBurn message to chain: {:?}, amount: {}", chain_id, amount);
+                BankrollResponse::Ok
+            }
+            BankrollOperation::UpdateBonusSchedule { schedule } => {
+                log::info!("\n\nBankrollOperation::UpdateBonusSchedule");
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BankrollOperation::UpdateBonusSchedule"
+                );
+                if let Err(error) = schedule.validate() {
+                    panic!("invalid bonus schedule: {}", error);
+                }
+
+                log::info!("New bonus schedule: {:?}", schedule);
+                self.state.bonus_schedule.set(Some(schedule.clone()));
+
+                // Push the schedule to every User Chain known to the Master Chain
+                let user_chains = self.state.user_chain_reports.indices().await.expect("Failed to read account report keys");
+                for chain_id in user_chains.into_iter() {
+                    self.message_manager(chain_id, BankrollMessage::BonusScheduleUpdate { schedule: schedule.clone() });
+                }
                 BankrollResponse::Ok
             }
         }
@@ -147,6 +476,10 @@ impl Contract for BankrollContract {
 
     async fn execute_message(&mut self, message: Self::Message) {
         let origin_chain_id = self.runtime.message_origin_chain_id().expect("Chain ID missing from message");
+        let Some(message) = message.into_current() else {
+            log::info!("Skipping message from {:?} that does not decode with this schema", origin_chain_id);
+            return;
+        };
 
         match message {
             // * Public Chain
@@ -162,9 +495,22 @@ impl Contract for BankrollContract {
                 let previous_balance = *current_token;
                 current_token.saturating_add_assign(amount);
                 log::info!("Token balance updated: {} -> {}", previous_balance, current_token);
+
+                // Update current balance to Master Chain
+                let master_chain = self.runtime.application_parameters().master_chain;
+                let amount = *current_token;
+                self.message_manager(master_chain, BankrollMessage::TokenUpdate { amount });
             }
             BankrollMessage::DebtNotif { debt_id, amount, created_at } => {
                 log::info!("\n\nBankrollMessage::DebtNotif");
+
+                // A bounced notification means the target chain never processed it
+                if self.runtime.message_is_bouncing().unwrap_or(false) {
+                    log::info!("DebtNotif debt_id: {} bounced back from {:?}", debt_id, origin_chain_id);
+                    self.mark_debt_rejected(debt_id, origin_chain_id).await;
+                    return;
+                }
+
                 log::info!(
                     "BankrollMessage::DebtNotif debt_id: {} from user_chain: {:?} amount: {} at {:?}",
                     debt_id,
@@ -173,48 +519,75 @@ impl Contract for BankrollContract {
                     self.runtime.chain_id()
                 );
 
-                // Verify we have sufficient tokens
-                let current_token = self.state.blackjack_token.get();
+                // Pay whatever the pool can cover
+                let current_token = *self.state.blackjack_token.get();
                 log::info!("Current token pool before debt payment: {}", current_token);
-                assert!(
-                    *current_token >= amount,
-                    "Insufficient tokens to pay debt. Available: {}, Required: {}",
-                    current_token,
-                    amount
-                );
-
-                // Subtract the debt amount from blackjack_token pool
-                let current_token_log = current_token.clone();
-                let remaining_token = current_token.saturating_sub(amount);
+                let payment = if current_token >= amount { amount } else { current_token };
+                let remaining_token = current_token.saturating_sub(payment);
                 self.state.blackjack_token.set(remaining_token);
 
                 log::info!(
-                    "Debt payment processed. Token pool: {} -> {}. Sending DebtPaid to {:?}",
-                    current_token_log,
+                    "Debt payment processed. Paid: {} of {}. Token pool: {} -> {}. Replying to {:?}",
+                    payment,
+                    amount,
+                    current_token,
                     remaining_token,
                     origin_chain_id
                 );
 
-                // Send DebtPaid message back to the user chain
                 let paid_at = self.runtime.system_time();
-                self.message_manager(origin_chain_id, BankrollMessage::DebtPaid { debt_id, amount, paid_at });
+                let status = if payment == amount {
+                    self.message_manager(origin_chain_id, BankrollMessage::DebtPaid { debt_id, amount, paid_at });
+                    DebtStatus::Paid
+                } else if payment > Amount::ZERO {
+                    self.message_manager(
+                        origin_chain_id,
+                        BankrollMessage::DebtPartiallyPaid {
+                            debt_id,
+                            amount: payment,
+                            paid_at,
+                        },
+                    );
+                    DebtStatus::PartiallyPaid
+                } else {
+                    self.message_manager(origin_chain_id, BankrollMessage::DebtRejected { debt_id, rejected_at: paid_at });
+                    DebtStatus::Rejected
+                };
 
-                // Log debt history
+                // Log debt history, debt ids are only unique per user chain
+                let debt_key = (origin_chain_id, debt_id);
+                let attempts = self
+                    .state
+                    .received_debts
+                    .get(&debt_key)
+                    .await
+                    .expect("Failed to get debt record")
+                    .map_or(1, |record| record.attempts.saturating_add(1));
                 let debt_record = DebtRecord {
                     id: debt_id,
                     user_chain: origin_chain_id,
+                    target_chain: self.runtime.chain_id(),
                     amount,
+                    paid_amount: payment,
+                    attempts,
                     created_at,
-                    paid_at: Some(paid_at),
-                    status: DebtStatus::Paid,
+                    sent_at: paid_at,
+                    paid_at: if payment > Amount::ZERO { Some(paid_at) } else { None },
+                    status,
                 };
-                self.state.debt_log.insert(&debt_id, debt_record.clone()).unwrap_or_else(|_| {
+                self.state.received_debts.insert(&debt_key, debt_record.clone()).unwrap_or_else(|_| {
                     panic!("Failed to create debt record for debt_id: {}", debt_id);
                 });
 
-                // Update current balance to Master Chain
+                // Report a shortfall (the pool is then empty) or the new balance, never both,
+                // so the Master Chain rebalances once per debt
                 let master_chain = self.runtime.application_parameters().master_chain;
-                self.message_manager(master_chain, BankrollMessage::TokenUpdate { amount: remaining_token });
+                if payment < amount {
+                    let shortfall = amount.saturating_sub(payment);
+                    self.message_manager(master_chain, BankrollMessage::LiquidityShortfall { amount: shortfall });
+                } else {
+                    self.message_manager(master_chain, BankrollMessage::TokenUpdate { amount: remaining_token });
+                }
             }
             BankrollMessage::TokenPot { amount } => {
                 log::info!("\n\nBankrollMessage::TokenPot");
@@ -250,7 +623,81 @@ impl Contract for BankrollContract {
                 let amount = *current_token;
                 self.message_manager(master_chain, BankrollMessage::TokenUpdate { amount });
             }
+            BankrollMessage::TransferLiquidity { to_chain, amount } => {
+                log::info!("\n\nBankrollMessage::TransferLiquidity");
+                let master_chain = self.runtime.application_parameters().master_chain;
+                assert_eq!(
+                    origin_chain_id, master_chain,
+                    "MasterChain Authorization Required for BankrollMessage::TransferLiquidity"
+                );
+
+                // Never move more than the pool currently holds
+                let current_token = *self.state.blackjack_token.get();
+                let transfer = amount.min(current_token);
+                let remaining_token = current_token.saturating_sub(transfer);
+                self.state.blackjack_token.set(remaining_token);
+
+                log::info!(
+                    "Liquidity transfer of {} (requested {}) to {:?}. Token pool: {} -> {}",
+                    transfer,
+                    amount,
+                    to_chain,
+                    current_token,
+                    remaining_token
+                );
+
+                // Always answer, even with nothing to send, so the Master Chain can close the transfer
+                self.message_manager(to_chain, BankrollMessage::LiquidityReceived { amount: transfer });
+                self.message_manager(master_chain, BankrollMessage::TokenUpdate { amount: remaining_token });
+            }
+            BankrollMessage::LiquidityReceived { amount } => {
+                log::info!("\n\nBankrollMessage::LiquidityReceived");
+                log::info!(
+                    "BankrollMessage::LiquidityReceived from {:?} amount: {} at {:?}",
+                    origin_chain_id,
+                    amount,
+                    self.runtime.chain_id()
+                );
+
+                let current_token = self.state.blackjack_token.get_mut();
+                current_token.saturating_add_assign(amount);
+                log::info!("Liquidity received. New total tokens: {}", current_token);
+
+                // Confirm the transfer landed along with the new balance
+                let master_chain = self.runtime.application_parameters().master_chain;
+                let amount = *current_token;
+                self.message_manager(master_chain, BankrollMessage::LiquidityLanded { amount });
+            }
+            BankrollMessage::TokenBurn { amount } => {
+                log::info!("\n\nBankrollMessage::TokenBurn");
+                let master_chain = self.runtime.application_parameters().master_chain;
+                assert_eq!(
+                    origin_chain_id, master_chain,
+                    "MasterChain Authorization Required for BankrollMessage::TokenBurn"
+                );
+
+                // Only burn what the pool currently holds
+                let current_token = *self.state.blackjack_token.get();
+                let burned = amount.min(current_token);
+                let remaining_token = current_token.saturating_sub(burned);
+                self.state.blackjack_token.set(remaining_token);
+
+                log::info!("Burned {} (requested {}). Token pool: {} -> {}", burned, amount, current_token, remaining_token);
+
+                self.message_manager(master_chain, BankrollMessage::TokenBurned { amount: burned });
+                self.message_manager(master_chain, BankrollMessage::TokenUpdate { amount: remaining_token });
+            }
             // * User Chain
+            BankrollMessage::BonusScheduleUpdate { schedule } => {
+                log::info!("\n\nBankrollMessage::BonusScheduleUpdate");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BankrollMessage::BonusScheduleUpdate"
+                );
+                log::info!("Bonus schedule updated at {:?}: {:?}", self.runtime.chain_id(), schedule);
+                self.state.bonus_schedule.set(Some(schedule));
+            }
             BankrollMessage::DebtPaid { debt_id, amount, paid_at } => {
                 log::info!("\n\nBankrollMessage::DebtPaid");
                 log::info!(
@@ -261,23 +708,60 @@ impl Contract for BankrollContract {
                     self.runtime.chain_id()
                 );
 
-                // Update the debt record with paid_at and status
-                let mut debt_record = self
-                    .state
-                    .debt_log
-                    .get(&debt_id)
-                    .await
-                    .expect("Failed to get debt record")
-                    .expect("Debt record not found");
+                let Some(mut debt_record) = self.debt_for_reply(debt_id, origin_chain_id).await else {
+                    return;
+                };
 
+                let previous_outstanding = debt_record.outstanding();
+                debt_record.paid_amount.saturating_add_assign(amount);
                 debt_record.paid_at = Some(paid_at);
                 debt_record.status = DebtStatus::Paid;
+                self.adjust_outstanding_debt(previous_outstanding, Amount::ZERO);
 
                 self.state.debt_log.insert(&debt_id, debt_record).unwrap_or_else(|_| {
                     panic!("Failed to update debt record for debt_id: {}", debt_id);
                 });
 
                 log::info!("Debt {} successfully updated to Paid status", debt_id);
+                self.report_user_chain();
+            }
+            BankrollMessage::DebtPartiallyPaid { debt_id, amount, paid_at } => {
+                log::info!("\n\nBankrollMessage::DebtPartiallyPaid");
+                log::info!(
+                    "BankrollMessage::DebtPartiallyPaid debt_id: {} amount: {} timestamp: {:?} at {:?}",
+                    debt_id,
+                    amount,
+                    paid_at,
+                    self.runtime.chain_id()
+                );
+
+                let Some(mut debt_record) = self.debt_for_reply(debt_id, origin_chain_id).await else {
+                    return;
+                };
+
+                let previous_outstanding = debt_record.outstanding();
+                debt_record.paid_amount.saturating_add_assign(amount);
+                debt_record.paid_at = Some(paid_at);
+                debt_record.status = DebtStatus::PartiallyPaid;
+                let outstanding = debt_record.outstanding();
+                self.adjust_outstanding_debt(previous_outstanding, outstanding);
+
+                self.state.debt_log.insert(&debt_id, debt_record).unwrap_or_else(|_| {
+                    panic!("Failed to update debt record for debt_id: {}", debt_id);
+                });
+
+                log::info!("Debt {} partially paid, outstanding: {}", debt_id, outstanding);
+                self.report_user_chain();
+            }
+            BankrollMessage::DebtRejected { debt_id, rejected_at } => {
+                log::info!("\n\nBankrollMessage::DebtRejected");
+                log::info!(
+                    "BankrollMessage::DebtRejected debt_id: {} from {:?} timestamp: {:?}",
+                    debt_id,
+                    origin_chain_id,
+                    rejected_at
+                );
+                self.mark_debt_rejected(debt_id, origin_chain_id).await;
             }
             // * Master Chain
             BankrollMessage::TokenUpdate { amount } => {
@@ -291,13 +775,60 @@ impl Contract for BankrollContract {
 
                 // TODO: verify that origin_chain_id is a Public Chain
 
-                let data = PublicChainBalances {
-                    chain: origin_chain_id,
+                self.record_public_chain_balance(origin_chain_id, amount);
+                self.refill_public_chain(origin_chain_id, amount, Amount::ZERO).await;
+            }
+            BankrollMessage::LiquidityShortfall { amount } => {
+                log::info!("\n\nBankrollMessage::LiquidityShortfall");
+                log::info!(
+                    "BankrollMessage::LiquidityShortfall from {:?} amount: {} at {:?}",
+                    origin_chain_id,
                     amount,
-                };
-                self.state.balances.insert(&origin_chain_id, data).unwrap_or_else(|_| {
-                    panic!("Failed to update record for Public Chain ID: {}", origin_chain_id);
+                    self.runtime.chain_id()
+                );
+
+                // The pool paid out everything it held before running short
+                self.record_public_chain_balance(origin_chain_id, Amount::ZERO);
+                self.refill_public_chain(origin_chain_id, Amount::ZERO, amount).await;
+            }
+            BankrollMessage::LiquidityLanded { amount } => {
+                log::info!("\n\nBankrollMessage::LiquidityLanded");
+                log::info!("BankrollMessage::LiquidityLanded from {:?} new balance: {}", origin_chain_id, amount);
+                self.state.rebalances_in_flight.remove(&origin_chain_id).unwrap_or_else(|_| {
+                    panic!("Failed to clear rebalance in flight for Public Chain ID: {}", origin_chain_id);
                 });
+                self.record_public_chain_balance(origin_chain_id, amount);
+                self.refill_public_chain(origin_chain_id, amount, Amount::ZERO).await;
+            }
+            BankrollMessage::TokenBurned { amount } => {
+                log::info!("\n\nBankrollMessage::TokenBurned");
+                log::info!("BankrollMessage::TokenBurned from {:?} amount: {}", origin_chain_id, amount);
+                let total_burned = self.state.total_burned.get_mut();
+                total_burned.saturating_add_assign(amount);
+                log::info!("Total burned supply: {}", total_burned);
+            }
+            BankrollMessage::AccountReport { report } => {
+                log::info!("\n\nBankrollMessage::AccountReport");
+                log::info!("BankrollMessage::AccountReport from {:?}: {:?}", origin_chain_id, report);
+
+                // First report from a User Chain, hand over the current bonus schedule
+                let is_new_chain = !self
+                    .state
+                    .user_chain_reports
+                    .contains_key(&origin_chain_id)
+                    .await
+                    .expect("Failed to read account report");
+                if let Some(schedule) = self.state.bonus_schedule.get().clone().filter(|_| is_new_chain) {
+                    self.message_manager(origin_chain_id, BankrollMessage::BonusScheduleUpdate { schedule });
+                }
+
+                self.state.user_chain_reports.insert(&origin_chain_id, report).unwrap_or_else(|_| {
+                    panic!("Failed to update account report for User Chain ID: {}", origin_chain_id);
+                });
+            }
+            // * Schema
+            BankrollMessage::Versioned { .. } => {
+                panic!("BankrollMessage::Versioned must not be nested");
             }
         }
     }
@@ -309,6 +840,212 @@ impl Contract for BankrollContract {
 
 impl BankrollContract {
     fn message_manager(&mut self, destination: ChainId, message: BankrollMessage) {
-        self.runtime.prepare_message(message).with_tracking().send_to(destination);
+        self.runtime
+            .prepare_message(BankrollMessage::versioned(&message))
+            .with_tracking()
+            .send_to(destination);
+    }
+
+    // * Master Chain
+    fn record_public_chain_balance(&mut self, chain: ChainId, amount: Amount) {
+        let data = PublicChainBalances { chain, amount };
+        self.state.balances.insert(&chain, data).unwrap_or_else(|_| {
+            panic!("Failed to update record for Public Chain ID: {}", chain);
+        });
+    }
+
+    /// Cover a shortfall and refill pools that fall below the low watermark.
+    async fn refill_public_chain(&mut self, target_chain: ChainId, balance: Amount, shortfall: Amount) {
+        let mut required = shortfall;
+        if let Some(low_watermark) = self.runtime.application_parameters().low_watermark {
+            if balance < low_watermark {
+                log::info!("Public Chain {:?} below low watermark: {} < {}", target_chain, balance, low_watermark);
+                required.saturating_add_assign(low_watermark.saturating_sub(balance));
+            }
+        }
+        if required > Amount::ZERO {
+            self.auto_rebalance(target_chain, required).await;
+        }
+    }
+
+    async fn auto_rebalance(&mut self, target_chain: ChainId, required: Amount) {
+        let Some(high_watermark) = self.runtime.application_parameters().high_watermark else {
+            log::info!("Automatic rebalancing disabled, high watermark not configured");
+            return;
+        };
+
+        // One transfer per target at a time, the next one waits for LiquidityLanded
+        if let Some(in_flight) = self.rebalance_in_flight(target_chain).await {
+            log::info!("Rebalance of {} to {:?} still in flight, skipping", in_flight, target_chain);
+            return;
+        }
+
+        // Pick the Public Chain with the largest liquidity above the high watermark
+        let balances_keys = self.state.balances.indices().await.expect("Failed to read balances keys");
+        let mut donor: Option<(ChainId, Amount)> = None;
+        for key in balances_keys.into_iter() {
+            if key == target_chain {
+                continue;
+            }
+            let balance = self
+                .state
+                .balances
+                .get(&key)
+                .await
+                .expect("Failed to get balances")
+                .expect("Failed to get balances");
+            let excess = balance.amount.saturating_sub(high_watermark);
+            if excess > donor.map_or(Amount::ZERO, |(_, amount)| amount) {
+                donor = Some((key, excess));
+            }
+        }
+
+        let Some((donor_chain, excess)) = donor else {
+            log::info!("No Public Chain above high watermark {} to cover {}", high_watermark, required);
+            return;
+        };
+
+        let amount = excess.min(required);
+        log::info!("Auto rebalancing {} from {:?} to {:?}", amount, donor_chain, target_chain);
+        self.send_rebalance(donor_chain, target_chain, amount).await;
+    }
+
+    async fn rebalance_in_flight(&self, target_chain: ChainId) -> Option<Amount> {
+        self.state
+            .rebalances_in_flight
+            .get(&target_chain)
+            .await
+            .expect("Failed to get rebalance in flight")
+    }
+
+    async fn send_rebalance(&mut self, donor_chain: ChainId, target_chain: ChainId, amount: Amount) {
+        self.message_manager(
+            donor_chain,
+            BankrollMessage::TransferLiquidity {
+                to_chain: target_chain,
+                amount,
+            },
+        );
+
+        self.state.rebalances_in_flight.insert(&target_chain, amount).unwrap_or_else(|_| {
+            panic!("Failed to record rebalance in flight for Public Chain ID: {}", target_chain);
+        });
+
+        // Reserve the amount on the donor until its next TokenUpdate arrives
+        let Some(mut donor_balance) = self.state.balances.get(&donor_chain).await.expect("Failed to get balances") else {
+            log::info!("No balance reported by {:?} yet, nothing to reserve", donor_chain);
+            return;
+        };
+        donor_balance.amount = donor_balance.amount.saturating_sub(amount);
+        self.state.balances.insert(&donor_chain, donor_balance).unwrap_or_else(|_| {
+            panic!("Failed to update record for Public Chain ID: {}", donor_chain);
+        });
+    }
+
+    // * User Chain
+    fn current_bonus_schedule(&mut self) -> BonusSchedule {
+        self.state
+            .bonus_schedule
+            .get()
+            .clone()
+            .unwrap_or_else(|| BonusSchedule::with_bonus(self.runtime.application_parameters().bonus))
+    }
+
+    fn claim_daily_bonus(&mut self, balance: Amount) -> Amount {
+        let schedule = self.current_bonus_schedule();
+        let current_time = self.runtime.system_time();
+        let bonus = self.state.daily_bonus.get_mut().claim_bonus(&schedule, balance, current_time);
+        self.state.bonus_issued.get_mut().saturating_add_assign(bonus);
+        bonus
+    }
+
+    async fn get_responsible_gaming(&self, owner: &AccountOwner) -> ResponsibleGaming {
+        self.state
+            .gaming_limits
+            .get(owner)
+            .await
+            .expect("Failed to get gaming limits")
+            .unwrap_or_default()
+    }
+
+    fn save_responsible_gaming(&mut self, owner: &AccountOwner, gaming: ResponsibleGaming) {
+        self.state.gaming_limits.insert(owner, gaming).unwrap_or_else(|_| {
+            panic!("Failed to update gaming limits for {:?}", owner);
+        });
+    }
+
+    async fn mark_debt_rejected(&mut self, debt_id: u64, origin_chain_id: ChainId) {
+        let Some(mut debt_record) = self.debt_for_reply(debt_id, origin_chain_id).await else {
+            return;
+        };
+
+        debt_record.status = DebtStatus::Rejected;
+
+        self.state.debt_log.insert(&debt_id, debt_record).unwrap_or_else(|_| {
+            panic!("Failed to update debt record for debt_id: {}", debt_id);
+        });
+
+        log::info!("Debt {} marked as Rejected, waiting for RetryDebt", debt_id);
+    }
+
+    /// Debt a public chain replied about, None when it is already paid or was sent again to another chain since.
+    async fn debt_for_reply(&self, debt_id: u64, origin_chain_id: ChainId) -> Option<DebtRecord> {
+        let debt_record = self
+            .state
+            .debt_log
+            .get(&debt_id)
+            .await
+            .expect("Failed to get debt record")
+            .expect("Debt record not found");
+
+        if debt_record.is_settled() {
+            log::info!("Debt {} is already paid, ignoring reply from {:?}", debt_id, origin_chain_id);
+            return None;
+        }
+        if debt_record.target_chain != origin_chain_id {
+            log::info!(
+                "Debt {} was sent to {:?}, ignoring reply from {:?}",
+                debt_id,
+                debt_record.target_chain,
+                origin_chain_id
+            );
+            return None;
+        }
+        Some(debt_record)
+    }
+
+    fn assert_application_caller(&mut self, operation: &str) {
+        // Operations submitted straight to the Bankroll have no caller application
+        assert!(
+            self.runtime.authenticated_caller_id().is_some(),
+            "Application Authorization Required for {}",
+            operation
+        );
+    }
+    fn adjust_player_balances(&mut self, previous: Amount, current: Amount) {
+        let total = self.state.player_balances.get_mut();
+        *total = total.saturating_sub(previous).saturating_add(current);
+    }
+
+    fn adjust_outstanding_debt(&mut self, previous: Amount, current: Amount) {
+        let total = self.state.outstanding_debt.get_mut();
+        *total = total.saturating_sub(previous).saturating_add(current);
+    }
+
+    fn report_user_chain(&mut self) {
+        let report = UserChainReport {
+            balance: *self.state.player_balances.get(),
+            outstanding_debt: *self.state.outstanding_debt.get(),
+            bonus_issued: *self.state.bonus_issued.get(),
+        };
+
+        // Skip reporting when nothing changed since the last report
+        if self.state.last_report.get().as_ref() == Some(&report) {
+            return;
+        }
+
+        self.state.last_report.set(Some(report.clone()));
+        let master_chain = self.runtime.application_parameters().master_chain;
+        self.message_manager(master_chain, BankrollMessage::AccountReport { report });
     }
 }
@@ -1,6 +1,7 @@
+use abi::schema::Envelope;
 use async_graphql::scalar;
-use async_graphql::{Request, Response, SimpleObject};
-use linera_sdk::linera_base_types::{AccountOwner, Amount, ChainId, Timestamp};
+use async_graphql::{InputObject, Request, Response, SimpleObject};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ChainId, TimeDelta, Timestamp};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
     linera_base_types::{ContractAbi, ServiceAbi},
@@ -25,10 +26,24 @@ pub enum BankrollOperation {
     // * User Chain
     Balance { owner: AccountOwner },
     UpdateBalance { owner: AccountOwner, amount: Amount },
+    ClaimBonus { owner: AccountOwner },
+    SetGamingLimits { owner: AccountOwner, limits: GamingLimits },
+    SelfExclude { owner: AccountOwner, duration_micros: u64 },
+    CheckBet { owner: AccountOwner, amount: Amount },
+    EndSession { owner: AccountOwner },
+    EscrowBuyIn { owner: AccountOwner, amount: Amount },  // applications only
+    ReleaseEscrow { owner: AccountOwner },                // applications only
+    SettleEscrow { owner: AccountOwner, amount: Amount }, // applications only
+    Escrow { owner: AccountOwner },
     NotifyDebt { amount: Amount, target_chain: ChainId },
+    RetryDebt { debt_id: u64, target_chain: ChainId },
+    Debt { debt_id: u64 },
     TransferTokenPot { amount: Amount, target_chain: ChainId },
     // * Master Chain
     MintToken { chain_id: ChainId, amount: Amount },
+    RebalanceLiquidity { from_chain: ChainId, to_chain: ChainId, amount: Amount },
+    Burn { chain_id: ChainId, amount: Amount },
+    UpdateBonusSchedule { schedule: BonusSchedule },
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -41,6 +56,38 @@ pub enum BankrollMessage {
     DebtPaid { debt_id: u64, amount: Amount, paid_at: Timestamp },
     // * Master Chain
     TokenUpdate { amount: Amount },
+    // * Schema
+    Versioned { envelope: Envelope }, // keep the variants above at their baseline index, add new variants below
+    // * Public Chain
+    TransferLiquidity { to_chain: ChainId, amount: Amount },
+    LiquidityReceived { amount: Amount },
+    TokenBurn { amount: Amount },
+    // * User Chain
+    BonusScheduleUpdate { schedule: BonusSchedule },
+    DebtPartiallyPaid { debt_id: u64, amount: Amount, paid_at: Timestamp },
+    DebtRejected { debt_id: u64, rejected_at: Timestamp },
+    // * Master Chain
+    LiquidityShortfall { amount: Amount },
+    TokenBurned { amount: Amount },
+    AccountReport { report: UserChainReport },
+    LiquidityLanded { amount: Amount }, // balance of the target pool once a rebalance arrived
+}
+
+impl BankrollMessage {
+    pub fn versioned(message: &BankrollMessage) -> Self {
+        BankrollMessage::Versioned {
+            envelope: Envelope::seal(message),
+        }
+    }
+
+    /// Unwrap a versioned message, None when it does not decode with this schema.
+    /// Bare messages come from the baseline bytecode, whose variants kept their index and layout.
+    pub fn into_current(self) -> Option<Self> {
+        match self {
+            BankrollMessage::Versioned { envelope } => envelope.open(),
+            message => Some(message),
+        }
+    }
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -48,18 +95,35 @@ pub enum BankrollResponse {
     #[default]
     Ok,
     Balance(Amount),
+    BetRefused(String),
+    Escrow(Amount),
+    Debt(Option<DebtRecord>),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BankrollParameters {
     pub master_chain: ChainId,
     pub bonus: Amount,
+    pub low_watermark: Option<Amount>,  // public chain pool below this gets refilled
+    pub high_watermark: Option<Amount>, // public chain pool above this can donate liquidity
+    pub max_supply: Option<Amount>,     // hard cap for minted minus burned tokens
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
 pub struct DailyBonus {
-    pub amount: Amount,
+    pub amount: Amount, // last claimed bonus
     pub last_claim: Timestamp,
+    pub streak: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "BonusScheduleInput")]
+pub struct BonusSchedule {
+    pub base_amount: Amount,
+    pub cooldown_micros: u64,
+    pub streak_window_micros: u64,        // grace period after the cooldown before the streak resets
+    pub streak_multipliers: Vec<u16>,     // percentage per streak day, the last entry applies to longer streaks
+    pub top_up_threshold: Option<Amount>, // only pay the bonus while the balance is below this
 }
 
 #[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
@@ -69,21 +133,259 @@ pub struct PublicChainBalances {
 }
 
 impl DailyBonus {
-    pub fn is_zero(&self) -> bool {
-        self.amount == Amount::ZERO
+    pub fn time_until_next_claim(&self, schedule: &BonusSchedule, current_time: Timestamp) -> u64 {
+        let delta_since_last_claim = current_time.delta_since(self.last_claim).as_micros();
+        schedule.cooldown_micros.saturating_sub(delta_since_last_claim)
     }
-    pub fn update_bonus(&mut self, bonus: Amount) {
-        if self.is_zero() {
-            self.amount = bonus;
+    pub fn claim_bonus(&mut self, schedule: &BonusSchedule, balance: Amount, current_time: Timestamp) -> Amount {
+        if self.time_until_next_claim(schedule, current_time) > 0 {
+            return Amount::ZERO;
         }
-    }
-    pub fn claim_bonus(&mut self, current_time: Timestamp) -> Amount {
+
+        // Streak continues only when claimed within the window after the cooldown
         let delta_since_last_claim = current_time.delta_since(self.last_claim).as_micros();
-        if delta_since_last_claim >= ONE_DAY_CLAIM_DURATION_IN_MICROS {
-            self.last_claim = current_time;
-            return self.amount;
+        let streak_deadline = schedule.cooldown_micros.saturating_add(schedule.streak_window_micros);
+        let streak = if self.streak > 0 && delta_since_last_claim < streak_deadline {
+            self.streak.saturating_add(1)
+        } else {
+            1
+        };
+
+        let mut bonus = schedule.bonus_for_streak(streak);
+        if let Some(threshold) = schedule.top_up_threshold {
+            if balance >= threshold {
+                return Amount::ZERO;
+            }
+            bonus = bonus.min(threshold.saturating_sub(balance));
+        }
+
+        self.amount = bonus;
+        self.last_claim = current_time;
+        self.streak = streak;
+        bonus
+    }
+}
+
+impl BonusSchedule {
+    pub fn with_bonus(bonus: Amount) -> Self {
+        BonusSchedule {
+            base_amount: bonus,
+            cooldown_micros: ONE_DAY_CLAIM_DURATION_IN_MICROS,
+            streak_window_micros: ONE_DAY_CLAIM_DURATION_IN_MICROS,
+            streak_multipliers: vec![100],
+            top_up_threshold: None,
+        }
+    }
+    pub fn multiplier(&self, streak: u32) -> u16 {
+        let index = streak.saturating_sub(1) as usize;
+        self.streak_multipliers.get(index).or(self.streak_multipliers.last()).copied().unwrap_or(100)
+    }
+    pub fn bonus_for_streak(&self, streak: u32) -> Amount {
+        let attos = u128::from(self.base_amount).saturating_mul(self.multiplier(streak) as u128) / 100;
+        Amount::from_attos(attos)
+    }
+    pub fn validate(&self) -> Result<(), String> {
+        if self.base_amount.is_zero() {
+            return Err("base amount must be greater than zero".to_string());
+        }
+        if self.cooldown_micros == 0 {
+            return Err("cooldown must be greater than zero".to_string());
+        }
+        if self.streak_window_micros == 0 || self.streak_window_micros > ONE_WEEK_DURATION_IN_MICROS {
+            return Err(format!("streak window must be between 1 and {} micros", ONE_WEEK_DURATION_IN_MICROS));
+        }
+        if self.streak_multipliers.is_empty() {
+            return Err("at least one streak multiplier is required".to_string());
+        }
+        if self
+            .streak_multipliers
+            .iter()
+            .any(|multiplier| *multiplier == 0 || *multiplier > MAX_STREAK_MULTIPLIER)
+        {
+            return Err(format!("streak multipliers must be between 1 and {} percent", MAX_STREAK_MULTIPLIER));
+        }
+        if self.top_up_threshold.is_some_and(|threshold| threshold.is_zero()) {
+            return Err("top up threshold must be greater than zero".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "GamingLimitsInput")]
+pub struct GamingLimits {
+    pub daily_loss_limit: Option<Amount>,
+    pub weekly_loss_limit: Option<Amount>,
+    pub max_single_bet: Option<Amount>,
+    pub session_time_cap_micros: Option<u64>,
+}
+
+fn is_looser_limit<T: PartialOrd>(new: &Option<T>, current: &Option<T>) -> bool {
+    match (new, current) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(new), Some(current)) => new > current,
+    }
+}
+
+fn tighter_limit<T: PartialOrd + Copy>(new: Option<T>, current: Option<T>) -> Option<T> {
+    if is_looser_limit(&new, &current) {
+        current
+    } else {
+        new
+    }
+}
+
+impl GamingLimits {
+    /// Returns true when any limit in `self` allows more than the same limit in `current`.
+    pub fn is_looser_than(&self, current: &GamingLimits) -> bool {
+        is_looser_limit(&self.daily_loss_limit, &current.daily_loss_limit)
+            || is_looser_limit(&self.weekly_loss_limit, &current.weekly_loss_limit)
+            || is_looser_limit(&self.max_single_bet, &current.max_single_bet)
+            || is_looser_limit(&self.session_time_cap_micros, &current.session_time_cap_micros)
+    }
+
+    /// Limits from `self` that are at least as tight as in `current`, the others stay as in `current`.
+    pub fn tightened_from(&self, current: &GamingLimits) -> GamingLimits {
+        GamingLimits {
+            daily_loss_limit: tighter_limit(self.daily_loss_limit, current.daily_loss_limit),
+            weekly_loss_limit: tighter_limit(self.weekly_loss_limit, current.weekly_loss_limit),
+            max_single_bet: tighter_limit(self.max_single_bet, current.max_single_bet),
+            session_time_cap_micros: tighter_limit(self.session_time_cap_micros, current.session_time_cap_micros),
+        }
+    }
+}
+
+/// Responsible-gaming state for one account owner.
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct ResponsibleGaming {
+    pub limits: GamingLimits,
+    pub pending_limits: Option<GamingLimits>, // looser limits waiting for the cooling-off delay
+    pub pending_effective_at: Option<Timestamp>,
+    pub self_excluded_until: Option<Timestamp>,
+    pub session_started_at: Option<Timestamp>,
+    pub session_ended_at: Option<Timestamp>, // the session resumes when play starts again before the break is over
+    pub daily_loss: Amount,
+    pub daily_window_start: Timestamp,
+    pub weekly_loss: Amount,
+    pub weekly_window_start: Timestamp,
+}
+
+impl ResponsibleGaming {
+    pub fn update_limits(&mut self, limits: GamingLimits, current_time: Timestamp) {
+        // Tighter fields apply immediately, looser ones wait for the cooling-off delay.
+        // A new update replaces any pending loosening.
+        let is_looser = limits.is_looser_than(&self.limits);
+        self.limits = limits.tightened_from(&self.limits);
+        if is_looser {
+            let effective_at = current_time.saturating_add(TimeDelta::from_micros(COOLING_OFF_DURATION_IN_MICROS));
+            self.pending_limits = Some(limits);
+            self.pending_effective_at = Some(effective_at);
+        } else {
+            self.pending_limits = None;
+            self.pending_effective_at = None;
+        }
+    }
+    pub fn self_exclude(&mut self, current_time: Timestamp, duration_micros: u64) {
+        let until = current_time.saturating_add(TimeDelta::from_micros(duration_micros));
+        // An exclusion can only be extended, never shortened
+        if self.self_excluded_until.is_none_or(|current| until > current) {
+            self.self_excluded_until = Some(until);
+        }
+        self.session_started_at = None;
+        self.session_ended_at = None;
+    }
+    pub fn refresh(&mut self, current_time: Timestamp) {
+        if self.pending_effective_at.is_some_and(|effective_at| current_time >= effective_at) {
+            if let Some(limits) = self.pending_limits.take() {
+                self.limits = limits;
+            }
+            self.pending_effective_at = None;
+        }
+        if current_time.delta_since(self.daily_window_start).as_micros() >= ONE_DAY_CLAIM_DURATION_IN_MICROS {
+            self.daily_loss = Amount::ZERO;
+            self.daily_window_start = current_time;
+        }
+        if current_time.delta_since(self.weekly_window_start).as_micros() >= ONE_WEEK_DURATION_IN_MICROS {
+            self.weekly_loss = Amount::ZERO;
+            self.weekly_window_start = current_time;
+        }
+    }
+    pub fn check_bet(&mut self, amount: Amount, current_time: Timestamp) -> Result<(), String> {
+        self.refresh(current_time);
+
+        if let Some(until) = self.self_excluded_until.filter(|until| current_time < *until) {
+            return Err(format!("self-excluded until {}", until));
+        }
+        if let Some(max_single_bet) = self.limits.max_single_bet.filter(|max| amount > *max) {
+            return Err(format!("bet exceeds the max single bet of {}", max_single_bet));
+        }
+        if let Some(limit) = self.limits.daily_loss_limit.filter(|limit| self.daily_loss.saturating_add(amount) > *limit) {
+            return Err(format!("bet could exceed the daily loss limit of {}", limit));
+        }
+        if let Some(limit) = self.limits.weekly_loss_limit.filter(|limit| self.weekly_loss.saturating_add(amount) > *limit) {
+            return Err(format!("bet could exceed the weekly loss limit of {}", limit));
+        }
+
+        // A new session only starts after a break, coming back sooner resumes the last one
+        let break_taken = self
+            .session_ended_at
+            .take()
+            .is_some_and(|ended_at| current_time.delta_since(ended_at).as_micros() >= SESSION_BREAK_DURATION_IN_MICROS);
+        if break_taken {
+            self.session_started_at = None;
+        }
+        let session_started_at = *self.session_started_at.get_or_insert(current_time);
+        if let Some(cap) = self.limits.session_time_cap_micros {
+            if current_time.delta_since(session_started_at).as_micros() > cap {
+                return Err("session time cap reached, please take a break".to_string());
+            }
+        }
+
+        Ok(())
+    }
+    /// Escrowing a table buy-in is refused when no bet could follow it, it neither starts nor resumes a session.
+    pub fn check_buy_in(&mut self, current_time: Timestamp) -> Result<(), String> {
+        self.refresh(current_time);
+
+        if let Some(until) = self.self_excluded_until.filter(|until| current_time < *until) {
+            return Err(format!("self-excluded until {}", until));
+        }
+        if let Some(limit) = self.limits.daily_loss_limit.filter(|limit| self.daily_loss >= *limit) {
+            return Err(format!("daily loss limit of {} reached", limit));
+        }
+        if let Some(limit) = self.limits.weekly_loss_limit.filter(|limit| self.weekly_loss >= *limit) {
+            return Err(format!("weekly loss limit of {} reached", limit));
+        }
+
+        let break_taken = self
+            .session_ended_at
+            .is_some_and(|ended_at| current_time.delta_since(ended_at).as_micros() >= SESSION_BREAK_DURATION_IN_MICROS);
+        let session_started_at = self.session_started_at.filter(|_| !break_taken);
+        if let Some((cap, started_at)) = self.limits.session_time_cap_micros.zip(session_started_at) {
+            if current_time.delta_since(started_at).as_micros() > cap {
+                return Err("session time cap reached, please take a break".to_string());
+            }
+        }
+
+        Ok(())
+    }
+    pub fn record_balance_change(&mut self, previous: Amount, current: Amount, current_time: Timestamp) {
+        self.refresh(current_time);
+        if current < previous {
+            let loss = previous.saturating_sub(current);
+            self.daily_loss.saturating_add_assign(loss);
+            self.weekly_loss.saturating_add_assign(loss);
+        } else {
+            let win = current.saturating_sub(previous);
+            self.daily_loss = self.daily_loss.saturating_sub(win);
+            self.weekly_loss = self.weekly_loss.saturating_sub(win);
+        }
+    }
+    pub fn end_session(&mut self, current_time: Timestamp) {
+        if self.session_started_at.is_some() {
+            self.session_ended_at = Some(current_time);
         }
-        Amount::ZERO
     }
 }
 
@@ -93,18 +395,114 @@ scalar!(DebtStatus);
 pub enum DebtStatus {
     Pending = 0,
     Paid = 1,
+    PartiallyPaid = 2,
+    Rejected = 3,
+    Expired = 4,
 }
 
 #[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
 pub struct DebtRecord {
     pub id: u64,
     pub user_chain: ChainId,
+    pub target_chain: ChainId,
     pub amount: Amount,
+    pub paid_amount: Amount,
+    pub attempts: u8,
     pub created_at: Timestamp,
+    pub sent_at: Timestamp, // last DebtNotif sent for this debt
     pub paid_at: Option<Timestamp>,
     pub status: DebtStatus,
 }
 
+impl DebtRecord {
+    pub fn outstanding(&self) -> Amount {
+        self.amount.saturating_sub(self.paid_amount)
+    }
+
+    /// Only a fully paid debt is settled, an expired debt stays outstanding.
+    pub fn is_settled(&self) -> bool {
+        self.status == DebtStatus::Paid
+    }
+
+    /// A Pending debt can be sent again once its DebtNotif has gone unanswered for a while.
+    pub fn is_retryable(&self, current_time: Timestamp) -> bool {
+        match self.status {
+            DebtStatus::PartiallyPaid | DebtStatus::Rejected => true,
+            DebtStatus::Pending => current_time.delta_since(self.sent_at).as_micros() >= DEBT_PENDING_RETRY_IN_MICROS,
+            DebtStatus::Paid | DebtStatus::Expired => false,
+        }
+    }
+
+    /// Only a debt the target chain answered can be sent to another chain, a Pending one may still be paid.
+    pub fn can_reroute(&self) -> bool {
+        matches!(self.status, DebtStatus::PartiallyPaid | DebtStatus::Rejected)
+    }
+
+    pub fn is_expired(&self, current_time: Timestamp) -> bool {
+        current_time.delta_since(self.created_at).as_micros() >= DEBT_EXPIRY_DURATION_IN_MICROS
+    }
+}
+
+/// Token holdings of a User Chain, reported to the Master Chain for supply reconciliation.
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct UserChainReport {
+    pub balance: Amount,
+    pub outstanding_debt: Amount,
+    pub bonus_issued: Amount,
+}
+
+/// Supply reconciliation computed on the Master Chain.
+///
+/// Expected supply is everything ever issued: `total_minted + bonus_issued - total_burned`.
+/// Accounted supply is what can be found in the economy: public chain pools plus player
+/// balances, net of outstanding debts (winnings already credited but not yet covered by a pool).
+/// Pots of rounds in play and messages in flight show up as temporary drift.
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct SupplyReconciliation {
+    pub total_minted: Amount,
+    pub total_burned: Amount,
+    pub bonus_issued: Amount,
+    pub public_pools: Amount,
+    pub player_balances: Amount,
+    pub outstanding_debts: Amount,
+    pub expected_supply: Amount,
+    pub accounted_supply: Amount,
+    pub drift: Amount,
+    pub balanced: bool,
+}
+
+impl SupplyReconciliation {
+    pub fn new(
+        total_minted: Amount,
+        total_burned: Amount,
+        bonus_issued: Amount,
+        public_pools: Amount,
+        player_balances: Amount,
+        outstanding_debts: Amount,
+    ) -> Self {
+        let expected_supply = total_minted.saturating_add(bonus_issued).saturating_sub(total_burned);
+        let accounted_supply = public_pools.saturating_add(player_balances).saturating_sub(outstanding_debts);
+        let drift = if expected_supply > accounted_supply {
+            expected_supply.saturating_sub(accounted_supply)
+        } else {
+            accounted_supply.saturating_sub(expected_supply)
+        };
+
+        SupplyReconciliation {
+            total_minted,
+            total_burned,
+            bonus_issued,
+            public_pools,
+            player_balances,
+            outstanding_debts,
+            expected_supply,
+            accounted_supply,
+            drift,
+            balanced: drift.is_zero(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
 pub struct TokenPotRecord {
     pub id: u64,
@@ -114,3 +512,9 @@ pub struct TokenPotRecord {
 }
 
 const ONE_DAY_CLAIM_DURATION_IN_MICROS: u64 = 60 * 60 * 24 * 1_000_000;
+const ONE_WEEK_DURATION_IN_MICROS: u64 = 7 * ONE_DAY_CLAIM_DURATION_IN_MICROS;
+const DEBT_EXPIRY_DURATION_IN_MICROS: u64 = ONE_WEEK_DURATION_IN_MICROS;
+const DEBT_PENDING_RETRY_IN_MICROS: u64 = 10 * 60 * 1_000_000;
+const COOLING_OFF_DURATION_IN_MICROS: u64 = ONE_DAY_CLAIM_DURATION_IN_MICROS;
+const MAX_STREAK_MULTIPLIER: u16 = 1000;
+const SESSION_BREAK_DURATION_IN_MICROS: u64 = 60 * 60 * 1_000_000;
@@ -5,7 +5,8 @@ mod state;
 use std::sync::Arc;
 
 use async_graphql::{EmptySubscription, Object, Schema};
-use bankroll::{BankrollOperation, DailyBonus, PublicChainBalances};
+use bankroll::{BankrollOperation, BonusSchedule, DailyBonus, DebtRecord, PublicChainBalances, ResponsibleGaming, SupplyReconciliation};
+use linera_sdk::linera_base_types::{AccountOwner, Amount};
 use linera_sdk::{graphql::GraphQLMutationRoot, linera_base_types::WithServiceAbi, views::View, Service, ServiceRuntime};
 
 use self::state::BankrollState;
@@ -59,6 +60,40 @@ impl QueryRoot {
         self.state.daily_bonus.get().clone()
     }
 
+    /// Read-only balance of an account, no bonus is claimed.
+    async fn get_account_balance(&self, owner: AccountOwner) -> Amount {
+        self.state.accounts.get(&owner).await.expect("Failed to get account").unwrap_or_default()
+    }
+
+    /// Table buy-in held in escrow for an account.
+    async fn get_escrow(&self, owner: AccountOwner) -> Amount {
+        self.state.escrows.get(&owner).await.expect("Failed to get escrow").unwrap_or_default()
+    }
+
+    async fn get_gaming_limits(&self, owner: AccountOwner) -> ResponsibleGaming {
+        self.state
+            .gaming_limits
+            .get(&owner)
+            .await
+            .expect("Failed to get gaming limits")
+            .unwrap_or_default()
+    }
+
+    async fn get_bonus_schedule(&self) -> Option<BonusSchedule> {
+        self.state.bonus_schedule.get().clone()
+    }
+
+    /// Microseconds left before the next daily bonus can be claimed, 0 when claimable.
+    async fn time_until_next_claim(&self) -> u64 {
+        let schedule = self
+            .state
+            .bonus_schedule
+            .get()
+            .clone()
+            .unwrap_or_else(|| BonusSchedule::with_bonus(Amount::ZERO));
+        self.state.daily_bonus.get().time_until_next_claim(&schedule, self.runtime.system_time())
+    }
+
     async fn get_balances(&self) -> Vec<PublicChainBalances> {
         let balances_keys = self.state.balances.indices().await.expect("Failed to read balances keys");
         let mut data = Vec::new();
@@ -70,4 +105,59 @@ impl QueryRoot {
 
         data
     }
+
+    async fn get_debts(&self) -> Vec<DebtRecord> {
+        let debt_keys = self.state.debt_log.indices().await.expect("Failed to read debt log keys");
+        let mut data = Vec::new();
+
+        for key in debt_keys.into_iter() {
+            let d = self.state.debt_log.get(&key).await.expect("Failed to get debt record");
+            data.push(d.expect("Failed to get debt record"));
+        }
+
+        data
+    }
+
+    async fn get_received_debts(&self) -> Vec<DebtRecord> {
+        let debt_keys = self.state.received_debts.indices().await.expect("Failed to read received debt keys");
+        let mut data = Vec::new();
+
+        for key in debt_keys.into_iter() {
+            let d = self.state.received_debts.get(&key).await.expect("Failed to get debt record");
+            data.push(d.expect("Failed to get debt record"));
+        }
+
+        data
+    }
+
+    async fn get_supply_reconciliation(&self) -> SupplyReconciliation {
+        let mut public_pools = Amount::ZERO;
+        let balances_keys = self.state.balances.indices().await.expect("Failed to read balances keys");
+        for key in balances_keys.into_iter() {
+            if let Some(p) = self.state.balances.get(&key).await.expect("Failed to get balances") {
+                public_pools.saturating_add_assign(p.amount);
+            }
+        }
+
+        let mut player_balances = Amount::ZERO;
+        let mut outstanding_debts = Amount::ZERO;
+        let mut bonus_issued = Amount::ZERO;
+        let report_keys = self.state.user_chain_reports.indices().await.expect("Failed to read account report keys");
+        for key in report_keys.into_iter() {
+            if let Some(r) = self.state.user_chain_reports.get(&key).await.expect("Failed to get account report") {
+                player_balances.saturating_add_assign(r.balance);
+                outstanding_debts.saturating_add_assign(r.outstanding_debt);
+                bonus_issued.saturating_add_assign(r.bonus_issued);
+            }
+        }
+
+        SupplyReconciliation::new(
+            *self.state.total_minted.get(),
+            *self.state.total_burned.get(),
+            bonus_issued,
+            public_pools,
+            player_balances,
+            outstanding_debts,
+        )
+    }
 }
@@ -1,4 +1,4 @@
-use bankroll::{DailyBonus, DebtRecord, PublicChainBalances, TokenPotRecord};
+use bankroll::{BonusSchedule, DailyBonus, DebtRecord, PublicChainBalances, ResponsibleGaming, TokenPotRecord, UserChainReport};
 use linera_sdk::linera_base_types::{AccountOwner, Amount, ChainId};
 use linera_sdk::views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext};
 
@@ -8,11 +8,24 @@ pub struct BankrollState {
     // All Chain
     pub blackjack_token: RegisterView<Amount>,
     pub debt_log: MapView<u64, DebtRecord>,
+    pub bonus_schedule: RegisterView<Option<BonusSchedule>>, // set by Master Chain, parameters bonus otherwise
     // Public Chain
     pub token_pot_log: MapView<u64, TokenPotRecord>,
+    #[graphql(skip)]
+    pub received_debts: MapView<(ChainId, u64), DebtRecord>, // keyed by (user chain, debt id)
     // User Chain
     pub daily_bonus: RegisterView<DailyBonus>,
     pub accounts: MapView<AccountOwner, Amount>,
+    pub escrows: MapView<AccountOwner, Amount>, // table buy-ins held while seated, not part of accounts
+    pub gaming_limits: MapView<AccountOwner, ResponsibleGaming>,
+    pub bonus_issued: RegisterView<Amount>,
+    pub player_balances: RegisterView<Amount>,  // running total of accounts and escrows
+    pub outstanding_debt: RegisterView<Amount>, // running total of unpaid debts, expired ones included
+    pub last_report: RegisterView<Option<UserChainReport>>,
     // Master Chain
     pub balances: MapView<ChainId, PublicChainBalances>,
+    pub rebalances_in_flight: MapView<ChainId, Amount>, // target chain -> transfer not yet landed
+    pub total_minted: RegisterView<Amount>,
+    pub total_burned: RegisterView<Amount>,
+    pub user_chain_reports: MapView<ChainId, UserChainReport>,
 }
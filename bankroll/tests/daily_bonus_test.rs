@@ -0,0 +1,112 @@
+use bankroll::{BonusSchedule, DailyBonus};
+use linera_sdk::linera_base_types::{Amount, Timestamp};
+
+const HOUR: u64 = 60 * 60 * 1_000_000;
+const DAY: u64 = 24 * HOUR;
+
+#[test]
+fn test_streak_grows_within_window() {
+    let schedule = BonusSchedule {
+        base_amount: Amount::from_tokens(10),
+        cooldown_micros: DAY,
+        streak_window_micros: DAY,
+        streak_multipliers: vec![100, 150, 200],
+        top_up_threshold: None,
+    };
+    let mut bonus = DailyBonus::default();
+
+    assert_eq!(bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(DAY)), Amount::from_tokens(10));
+    assert_eq!(bonus.streak, 1);
+    // Still cooling down
+    assert_eq!(bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(DAY + HOUR)), Amount::ZERO);
+    assert_eq!(bonus.time_until_next_claim(&schedule, Timestamp::from(DAY + HOUR)), DAY - HOUR);
+
+    assert_eq!(
+        bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(2 * DAY + HOUR)),
+        Amount::from_tokens(15)
+    );
+    assert_eq!(
+        bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(3 * DAY + HOUR)),
+        Amount::from_tokens(20)
+    );
+    // The last multiplier applies to longer streaks
+    assert_eq!(
+        bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(4 * DAY + HOUR)),
+        Amount::from_tokens(20)
+    );
+    assert_eq!(bonus.streak, 4);
+}
+
+#[test]
+fn test_streak_resets_after_window() {
+    let schedule = BonusSchedule {
+        base_amount: Amount::from_tokens(10),
+        cooldown_micros: DAY,
+        streak_window_micros: DAY,
+        streak_multipliers: vec![100, 200],
+        top_up_threshold: None,
+    };
+    let mut bonus = DailyBonus::default();
+
+    bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(DAY));
+    bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(2 * DAY));
+    assert_eq!(bonus.streak, 2);
+    // Claimed exactly at cooldown plus window, the streak is lost
+    assert_eq!(bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(4 * DAY)), Amount::from_tokens(10));
+    assert_eq!(bonus.streak, 1);
+}
+
+#[test]
+fn test_top_up_pays_only_up_to_threshold() {
+    let schedule = BonusSchedule {
+        base_amount: Amount::from_tokens(10),
+        cooldown_micros: DAY,
+        streak_window_micros: DAY,
+        streak_multipliers: vec![100],
+        top_up_threshold: Some(Amount::from_tokens(25)),
+    };
+
+    let mut bonus = DailyBonus::default();
+    assert_eq!(
+        bonus.claim_bonus(&schedule, Amount::from_tokens(20), Timestamp::from(DAY)),
+        Amount::from_tokens(5)
+    );
+
+    // At or above the threshold nothing is paid and the claim is not used up
+    let mut bonus = DailyBonus::default();
+    assert_eq!(bonus.claim_bonus(&schedule, Amount::from_tokens(25), Timestamp::from(DAY)), Amount::ZERO);
+    assert_eq!(bonus.streak, 0);
+    assert_eq!(bonus.claim_bonus(&schedule, Amount::ZERO, Timestamp::from(DAY)), Amount::from_tokens(10));
+}
+
+#[test]
+fn test_validate_bonus_schedule() {
+    let mut schedule = BonusSchedule::with_bonus(Amount::from_tokens(10));
+    assert_eq!(schedule.validate(), Ok(()));
+
+    schedule.cooldown_micros = 0;
+    assert!(schedule.validate().is_err());
+    schedule.cooldown_micros = DAY;
+
+    schedule.streak_window_micros = 0;
+    assert!(schedule.validate().is_err());
+    schedule.streak_window_micros = 8 * DAY;
+    assert!(schedule.validate().is_err());
+    schedule.streak_window_micros = DAY;
+
+    schedule.streak_multipliers = vec![];
+    assert!(schedule.validate().is_err());
+    schedule.streak_multipliers = vec![100, 0];
+    assert!(schedule.validate().is_err());
+    schedule.streak_multipliers = vec![100, 1001];
+    assert!(schedule.validate().is_err());
+    schedule.streak_multipliers = vec![100, 1000];
+
+    schedule.top_up_threshold = Some(Amount::ZERO);
+    assert!(schedule.validate().is_err());
+    schedule.top_up_threshold = Some(Amount::from_tokens(1));
+    assert_eq!(schedule.validate(), Ok(()));
+
+    schedule.base_amount = Amount::ZERO;
+    assert!(schedule.validate().is_err());
+}
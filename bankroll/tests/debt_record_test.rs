@@ -0,0 +1,86 @@
+use bankroll::{DebtRecord, DebtStatus};
+use linera_sdk::linera_base_types::{Amount, ChainId, Timestamp};
+
+const MINUTE: u64 = 60 * 1_000_000;
+const DAY: u64 = 24 * 60 * MINUTE;
+
+#[test]
+fn test_outstanding_after_partial_payment() {
+    let debt = DebtRecord {
+        id: 1,
+        user_chain: format!("{:064x}", 1).parse::<ChainId>().unwrap(),
+        target_chain: format!("{:064x}", 2).parse::<ChainId>().unwrap(),
+        amount: Amount::from_tokens(100),
+        paid_amount: Amount::from_tokens(30),
+        attempts: 1,
+        created_at: Timestamp::from(0),
+        sent_at: Timestamp::from(0),
+        paid_at: Some(Timestamp::from(MINUTE)),
+        status: DebtStatus::PartiallyPaid,
+    };
+    assert_eq!(debt.outstanding(), Amount::from_tokens(70));
+    assert!(!debt.is_settled());
+    assert!(debt.is_retryable(Timestamp::from(MINUTE)));
+    assert!(debt.can_reroute());
+}
+
+#[test]
+fn test_pending_debt_is_retryable_once_stale() {
+    let debt = DebtRecord {
+        id: 1,
+        user_chain: format!("{:064x}", 1).parse::<ChainId>().unwrap(),
+        target_chain: format!("{:064x}", 2).parse::<ChainId>().unwrap(),
+        amount: Amount::from_tokens(100),
+        paid_amount: Amount::ZERO,
+        attempts: 1,
+        created_at: Timestamp::from(0),
+        sent_at: Timestamp::from(DAY),
+        paid_at: None,
+        status: DebtStatus::Pending,
+    };
+    // Staleness counts from the last send, not from creation
+    assert!(!debt.is_retryable(Timestamp::from(DAY + MINUTE)));
+    assert!(debt.is_retryable(Timestamp::from(DAY + 10 * MINUTE)));
+    // Still in flight, so it is only sent again to the same chain
+    assert!(!debt.can_reroute());
+}
+
+#[test]
+fn test_expired_debt_stays_outstanding() {
+    let debt = DebtRecord {
+        id: 1,
+        user_chain: format!("{:064x}", 1).parse::<ChainId>().unwrap(),
+        target_chain: format!("{:064x}", 2).parse::<ChainId>().unwrap(),
+        amount: Amount::from_tokens(100),
+        paid_amount: Amount::from_tokens(40),
+        attempts: 3,
+        created_at: Timestamp::from(0),
+        sent_at: Timestamp::from(DAY),
+        paid_at: Some(Timestamp::from(DAY)),
+        status: DebtStatus::Expired,
+    };
+    assert!(debt.is_expired(Timestamp::from(7 * DAY)));
+    assert!(!debt.is_expired(Timestamp::from(7 * DAY - 1)));
+    assert!(!debt.is_settled());
+    assert!(!debt.is_retryable(Timestamp::from(7 * DAY)));
+    assert_eq!(debt.outstanding(), Amount::from_tokens(60));
+}
+
+#[test]
+fn test_paid_debt_is_settled() {
+    let debt = DebtRecord {
+        id: 1,
+        user_chain: format!("{:064x}", 1).parse::<ChainId>().unwrap(),
+        target_chain: format!("{:064x}", 2).parse::<ChainId>().unwrap(),
+        amount: Amount::from_tokens(100),
+        paid_amount: Amount::from_tokens(100),
+        attempts: 1,
+        created_at: Timestamp::from(0),
+        sent_at: Timestamp::from(0),
+        paid_at: Some(Timestamp::from(MINUTE)),
+        status: DebtStatus::Paid,
+    };
+    assert!(debt.is_settled());
+    assert!(!debt.is_retryable(Timestamp::from(DAY)));
+    assert_eq!(debt.outstanding(), Amount::ZERO);
+}
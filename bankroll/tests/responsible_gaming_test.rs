@@ -0,0 +1,182 @@
+use bankroll::{GamingLimits, ResponsibleGaming};
+use linera_sdk::linera_base_types::{Amount, Timestamp};
+
+const MINUTE: u64 = 60 * 1_000_000;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+#[test]
+fn test_is_looser_than() {
+    let current = GamingLimits {
+        daily_loss_limit: Some(Amount::from_tokens(100)),
+        weekly_loss_limit: None,
+        max_single_bet: Some(Amount::from_tokens(10)),
+        session_time_cap_micros: None,
+    };
+
+    // Same limits are not looser
+    assert!(!current.is_looser_than(&current));
+
+    // Lowering a limit or adding a new one is tighter
+    let tighter = GamingLimits {
+        daily_loss_limit: Some(Amount::from_tokens(50)),
+        weekly_loss_limit: Some(Amount::from_tokens(200)),
+        ..current.clone()
+    };
+    assert!(!tighter.is_looser_than(&current));
+
+    // Raising a limit or removing it is looser
+    let raised = GamingLimits {
+        max_single_bet: Some(Amount::from_tokens(20)),
+        ..current.clone()
+    };
+    assert!(raised.is_looser_than(&current));
+    let removed = GamingLimits {
+        daily_loss_limit: None,
+        ..current.clone()
+    };
+    assert!(removed.is_looser_than(&current));
+}
+
+#[test]
+fn test_update_limits_applies_tighter_fields_immediately() {
+    let mut gaming = ResponsibleGaming::default();
+    gaming.update_limits(
+        GamingLimits {
+            daily_loss_limit: Some(Amount::from_tokens(100)),
+            weekly_loss_limit: None,
+            max_single_bet: Some(Amount::from_tokens(10)),
+            session_time_cap_micros: None,
+        },
+        Timestamp::from(0),
+    );
+    assert_eq!(gaming.pending_limits, None);
+
+    // Tighter daily loss limit together with a looser max single bet
+    let requested = GamingLimits {
+        daily_loss_limit: Some(Amount::from_tokens(50)),
+        weekly_loss_limit: None,
+        max_single_bet: Some(Amount::from_tokens(20)),
+        session_time_cap_micros: None,
+    };
+    gaming.update_limits(requested.clone(), Timestamp::from(HOUR));
+    assert_eq!(gaming.limits.daily_loss_limit, Some(Amount::from_tokens(50)));
+    assert_eq!(gaming.limits.max_single_bet, Some(Amount::from_tokens(10)));
+    assert_eq!(gaming.pending_limits, Some(requested.clone()));
+
+    // The looser field only applies after the cooling-off delay
+    gaming.refresh(Timestamp::from(HOUR + DAY - 1));
+    assert_eq!(gaming.limits.max_single_bet, Some(Amount::from_tokens(10)));
+    gaming.refresh(Timestamp::from(HOUR + DAY));
+    assert_eq!(gaming.limits, requested);
+    assert_eq!(gaming.pending_limits, None);
+}
+
+#[test]
+fn test_check_bet_limits() {
+    let mut gaming = ResponsibleGaming::default();
+    gaming.update_limits(
+        GamingLimits {
+            daily_loss_limit: Some(Amount::from_tokens(100)),
+            weekly_loss_limit: Some(Amount::from_tokens(150)),
+            max_single_bet: Some(Amount::from_tokens(30)),
+            session_time_cap_micros: None,
+        },
+        Timestamp::from(0),
+    );
+
+    assert_eq!(gaming.check_bet(Amount::from_tokens(30), Timestamp::from(MINUTE)), Ok(()));
+    assert!(gaming.check_bet(Amount::from_tokens(31), Timestamp::from(MINUTE)).is_err());
+
+    // 80 lost today, a bet of 30 could take the day past 100
+    gaming.record_balance_change(Amount::from_tokens(100), Amount::from_tokens(20), Timestamp::from(MINUTE));
+    assert!(gaming.check_bet(Amount::from_tokens(30), Timestamp::from(2 * MINUTE)).is_err());
+    assert_eq!(gaming.check_bet(Amount::from_tokens(20), Timestamp::from(2 * MINUTE)), Ok(()));
+
+    // A new day resets the daily loss but the weekly loss still counts
+    gaming.record_balance_change(Amount::from_tokens(100), Amount::from_tokens(40), Timestamp::from(DAY));
+    assert_eq!(gaming.daily_loss, Amount::from_tokens(60));
+    assert_eq!(gaming.weekly_loss, Amount::from_tokens(140));
+    assert!(gaming.check_bet(Amount::from_tokens(20), Timestamp::from(DAY + MINUTE)).is_err());
+    assert_eq!(gaming.check_bet(Amount::from_tokens(10), Timestamp::from(DAY + MINUTE)), Ok(()));
+}
+
+#[test]
+fn test_check_bet_self_exclusion() {
+    let mut gaming = ResponsibleGaming::default();
+    gaming.self_exclude(Timestamp::from(0), DAY);
+    assert!(gaming.check_bet(Amount::from_tokens(1), Timestamp::from(DAY - 1)).is_err());
+    assert_eq!(gaming.check_bet(Amount::from_tokens(1), Timestamp::from(DAY)), Ok(()));
+
+    // An exclusion can't be shortened
+    gaming.self_exclude(Timestamp::from(DAY), 2 * DAY);
+    gaming.self_exclude(Timestamp::from(DAY), HOUR);
+    assert_eq!(gaming.self_excluded_until, Some(Timestamp::from(3 * DAY)));
+}
+
+#[test]
+fn test_session_cap_needs_a_break() {
+    let mut gaming = ResponsibleGaming::default();
+    gaming.update_limits(
+        GamingLimits {
+            session_time_cap_micros: Some(HOUR),
+            ..GamingLimits::default()
+        },
+        Timestamp::from(0),
+    );
+
+    assert_eq!(gaming.check_bet(Amount::from_tokens(1), Timestamp::from(0)), Ok(()));
+    assert!(gaming.check_bet(Amount::from_tokens(1), Timestamp::from(HOUR + 1)).is_err());
+
+    // Leaving and coming straight back resumes the capped session
+    gaming.end_session(Timestamp::from(HOUR + 1));
+    assert!(gaming.check_bet(Amount::from_tokens(1), Timestamp::from(HOUR + MINUTE)).is_err());
+
+    // After a full break a new session starts
+    gaming.end_session(Timestamp::from(HOUR + MINUTE));
+    assert_eq!(gaming.check_bet(Amount::from_tokens(1), Timestamp::from(2 * HOUR + MINUTE)), Ok(()));
+    assert_eq!(gaming.session_started_at, Some(Timestamp::from(2 * HOUR + MINUTE)));
+}
+
+#[test]
+fn test_check_buy_in() {
+    let mut gaming = ResponsibleGaming::default();
+    gaming.update_limits(
+        GamingLimits {
+            daily_loss_limit: Some(Amount::from_tokens(50)),
+            session_time_cap_micros: Some(HOUR),
+            ..GamingLimits::default()
+        },
+        Timestamp::from(0),
+    );
+    assert_eq!(gaming.check_buy_in(Timestamp::from(0)), Ok(()));
+    // A buy-in does not start the session clock
+    assert_eq!(gaming.session_started_at, None);
+
+    gaming.record_balance_change(Amount::from_tokens(50), Amount::ZERO, Timestamp::from(MINUTE));
+    assert!(gaming.check_buy_in(Timestamp::from(2 * MINUTE)).is_err());
+    assert_eq!(gaming.check_buy_in(Timestamp::from(DAY + MINUTE)), Ok(()));
+
+    assert_eq!(gaming.check_bet(Amount::from_tokens(1), Timestamp::from(DAY + MINUTE)), Ok(()));
+    assert!(gaming.check_buy_in(Timestamp::from(DAY + 2 * HOUR)).is_err());
+    gaming.end_session(Timestamp::from(DAY + 2 * HOUR));
+    assert_eq!(gaming.check_buy_in(Timestamp::from(DAY + 3 * HOUR)), Ok(()));
+
+    gaming.self_exclude(Timestamp::from(DAY + 3 * HOUR), DAY);
+    assert!(gaming.check_buy_in(Timestamp::from(2 * DAY)).is_err());
+}
+
+#[test]
+fn test_record_balance_change() {
+    let mut gaming = ResponsibleGaming::default();
+    gaming.record_balance_change(Amount::from_tokens(50), Amount::from_tokens(20), Timestamp::from(0));
+    assert_eq!(gaming.daily_loss, Amount::from_tokens(30));
+    assert_eq!(gaming.weekly_loss, Amount::from_tokens(30));
+
+    // Wins reduce the tracked losses but never below zero
+    gaming.record_balance_change(Amount::from_tokens(20), Amount::from_tokens(30), Timestamp::from(MINUTE));
+    assert_eq!(gaming.daily_loss, Amount::from_tokens(20));
+    gaming.record_balance_change(Amount::from_tokens(30), Amount::from_tokens(100), Timestamp::from(MINUTE));
+    assert_eq!(gaming.daily_loss, Amount::ZERO);
+    assert_eq!(gaming.weekly_loss, Amount::ZERO);
+}
@@ -0,0 +1,48 @@
+use abi::schema::SCHEMA_VERSION;
+use bankroll::BankrollMessage;
+use linera_sdk::linera_base_types::{Amount, Timestamp};
+
+// BCS payloads as sent by the released bytecode from before versioned envelopes, do not regenerate
+const LEGACY_TOKEN_POT: &[u8] = &[2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const LEGACY_DEBT_PAID: &[u8] = &[
+    3, 7, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+];
+const LEGACY_TOKEN_UPDATE: &[u8] = &[4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+// TokenPot { amount: 5 attos } inside a schema version 1 envelope
+const VERSIONED_TOKEN_POT: &[u8] = &[5, 1, 0, 17, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+// Schema version 2 envelope holding a variant unknown to this bytecode
+const FUTURE_MESSAGE: &[u8] = &[5, 2, 0, 1, 40];
+
+fn decode_message(bytes: &[u8]) -> Option<BankrollMessage> {
+    bcs::from_bytes::<BankrollMessage>(bytes).expect("fixture must decode").into_current()
+}
+
+#[test]
+fn test_legacy_messages_decode() {
+    let amount = Amount::from_attos(5);
+    assert_eq!(decode_message(LEGACY_TOKEN_POT), Some(BankrollMessage::TokenPot { amount }));
+    assert_eq!(
+        decode_message(LEGACY_DEBT_PAID),
+        Some(BankrollMessage::DebtPaid {
+            debt_id: 7,
+            amount,
+            paid_at: Timestamp::from(1),
+        })
+    );
+    assert_eq!(decode_message(LEGACY_TOKEN_UPDATE), Some(BankrollMessage::TokenUpdate { amount: Amount::ZERO }));
+}
+
+#[test]
+fn test_versioned_message_decodes() {
+    let amount = Amount::from_attos(5);
+    assert_eq!(decode_message(VERSIONED_TOKEN_POT), Some(BankrollMessage::TokenPot { amount }));
+    let sealed = BankrollMessage::versioned(&BankrollMessage::TokenPot { amount });
+    let mut expected = VERSIONED_TOKEN_POT.to_vec();
+    expected[1..3].copy_from_slice(&SCHEMA_VERSION.to_le_bytes());
+    assert_eq!(bcs::to_bytes(&sealed).unwrap(), expected);
+}
+
+#[test]
+fn test_future_message_is_skipped() {
+    assert_eq!(decode_message(FUTURE_MESSAGE), None);
+}
@@ -3,11 +3,19 @@
 mod state;
 
 use self::state::BlackjackState;
-use abi::blackjack::{BlackjackGame, BlackjackStatus, GameOutcome, MutationReason, UserStatus, BLACKJACK_STREAM_NAME, MAX_BLACKJACK_PLAYERS};
+use abi::bet_chip_profile::{chip_stack_value, double_chip_stack, BetData, ChipPlacement, TableStakes};
+use abi::blackjack::{
+    check_public_chain_retirement, hash_invite_code, next_waitlist_match, select_play_chain, validate_invite_code, BlackjackGame, BlackjackStatus, GameOutcome,
+    MutationReason, PendingRequest, PlayChainHealth, PlayChainHeartbeat, PlayChainMode, PlayerAction, PrivateTable, PublicChainStatus, RoundSettlement,
+    RuleVariant, SeatOffer, SeatSettlement, TableFilter, TableListing, TableSeatResult, UserStatus, WaitlistEntry, BLACKJACK_STREAM_NAME, CHAT_STREAM_NAME,
+    MAX_BLACKJACK_PLAYERS, MAX_PRIVATE_TABLES_PER_OWNER, MAX_WAITLIST_LENGTH, TABLE_BET_DURATION_IN_MICROS, TABLE_SNAPSHOT_INTERVAL,
+    TABLE_TURN_DURATION_IN_MICROS,
+};
+use abi::chat::{is_chat_rate_limited, push_chat_history, sanitize_chat_text, ChatEntry, Emote, MAX_CHAT_MESSAGE_LENGTH};
 use abi::deck::{calculate_hand_value, format_card, get_new_deck, Deck};
 use abi::player_dealer::Player;
 use abi::random::get_random_value;
-use bankroll::{BankrollOperation, BankrollResponse};
+use bankroll::{BankrollOperation, BankrollResponse, DebtRecord};
 use blackjack::{BlackjackEvent, BlackjackMessage, BlackjackOperation, BlackjackParameters};
 use linera_sdk::linera_base_types::{Amount, ChainId, StreamUpdate};
 use linera_sdk::{
@@ -18,6 +26,7 @@ use linera_sdk::{
 
 const ONE_MINUTE_DURATION_IN_MICROS: u64 = 60 * 1_000_000;
 const TWO_MINUTES_DURATION_IN_MICROS: u64 = 120 * 1_000_000;
+const REQUEST_DEADLINE_DURATION_IN_MICROS: u64 = 30 * 1_000_000;
 
 const MINIMUM_BLACKJACK_DECK: u64 = 80;
 const REFILL_BLACKJACK_DECK_COUNT: u64 = 364;
@@ -52,6 +61,16 @@ impl Contract for BlackjackContract {
     }
 
     async fn execute_operation(&mut self, operation: Self::Operation) -> Self::Response {
+        // Operations that start or cancel a request replace the expired one instead
+        if !matches!(
+            operation,
+            BlackjackOperation::FindPlayChain { .. } | BlackjackOperation::RequestTableSeat { .. } | BlackjackOperation::CancelRequest {}
+        ) {
+            self.resend_expired_request();
+        }
+        // Seat offers expire and move down the waiting list with every change to the table
+        self.offer_free_seats().await;
+
         match operation {
             // * User Chain
             BlackjackOperation::SubscribeTo { chain_id } => {
@@ -64,32 +83,24 @@ impl Contract for BlackjackContract {
                 self.message_manager(chain_id, BlackjackMessage::Unsubscribe);
                 log::info!("Sent Unsubscribe message to chain_id: {:?}", chain_id);
             }
-            BlackjackOperation::FindPlayChain {} => {
-                log::info!("\n\nBlackjackOperation::FindPlayChain");
-
-                match self.state.user_status.get() {
-                    UserStatus::FindPlayChain => {
-                        panic!("still waiting response from previous FindPlayChain");
-                    }
-                    UserStatus::InMultiPlayerGame | UserStatus::InSinglePlayerGame => {
-                        panic!("user already in game, FindPlayChain not allowed");
-                    }
-                    UserStatus::RequestingTableSeat => {
-                        panic!("user is requesting table seat, FindPlayChain not allowed");
-                    }
-                    UserStatus::PlayChainFound | UserStatus::RequestTableSeatFail => {
-                        let play_chain_id = self.state.user_play_chain.get().unwrap();
-                        self.message_manager(play_chain_id, BlackjackMessage::Unsubscribe);
-                        self.state.user_play_chain.set(None);
-                    }
-                    _ => {}
-                }
+            BlackjackOperation::RefreshPublicChains {} => {
+                log::info!("\n\nBlackjackOperation::RefreshPublicChains");
+                let master_chain = self.runtime.application_parameters().master_chain;
+                self.message_manager(master_chain, BlackjackMessage::RequestPublicChains);
+                log::info!("Sent RequestPublicChains message to master chain: {:?}", master_chain);
+            }
+            BlackjackOperation::FindPlayChain { filter } => {
+                log::info!("\n\nBlackjackOperation::FindPlayChain filter: {:?}", filter);
+                self.release_found_play_chain();
 
                 let chain_id = self.get_public_chain();
                 log::info!("Selected public chain: {:?} for FindPlayChain query", chain_id);
                 self.state.user_status.set(UserStatus::FindPlayChain);
                 self.state.find_play_chain_retry.set(0);
-                self.message_manager(chain_id, BlackjackMessage::FindPlayChain);
+                let filter = filter.unwrap_or_default();
+                self.state.find_play_chain_filter.set(filter.clone());
+                let request_id = self.start_request();
+                self.send_request(chain_id, BlackjackMessage::FindPlayChain { filter, request_id });
                 log::info!("Sent FindPlayChain message to public chain: {:?}", chain_id);
             }
             BlackjackOperation::RequestTableSeat { seat_id } => {
@@ -102,76 +113,148 @@ impl Contract for BlackjackContract {
                     panic!("seat_id is invalid, can only be 1-{:?}", MAX_BLACKJACK_PLAYERS);
                 }
 
+                let request_in_flight = self.is_request_in_flight();
                 match self.state.user_status.get() {
                     UserStatus::Idle | UserStatus::FindPlayChain | UserStatus::PlayChainUnavailable => {
                         panic!("please call FindPlayChain first");
                     }
-                    UserStatus::RequestingTableSeat => {
+                    UserStatus::RequestingTableSeat if request_in_flight => {
                         panic!("still waiting response from previous RequestTableSeat");
                     }
                     UserStatus::InMultiPlayerGame | UserStatus::InSinglePlayerGame => {
                         panic!("user already in game, can't request new seat");
                     }
+                    UserStatus::Spectating => {
+                        panic!("user is spectating, please StopSpectating first");
+                    }
                     _ => {}
                 }
 
-                let balance = self.state.profile.get().balance;
+                if self.state.pending_leave.get().is_some() {
+                    panic!("the last table is still releasing your seat, please wait");
+                }
+
+                // The balance claimed to the Play Chain is backed by escrow, bounded once the table stakes are known
+                let balance = self.bankroll_get_balance();
+                let balance = self.bankroll_escrow_buy_in(balance);
                 let play_chain_id = self.state.user_play_chain.get().unwrap();
                 log::info!("Requesting seat_id: {} on play_chain: {:?} with balance: {}", seat_id, play_chain_id, balance);
-                self.message_manager(play_chain_id, BlackjackMessage::RequestTableSeat { seat_id, balance });
+                let request_id = self.start_request();
+                self.send_request(play_chain_id, BlackjackMessage::RequestTableSeat { seat_id, balance, request_id });
                 self.state.user_status.set(UserStatus::RequestingTableSeat);
                 log::info!("Sent RequestTableSeat message to play_chain: {:?}", play_chain_id);
             }
+            BlackjackOperation::CancelRequest {} => {
+                log::info!("\n\nBlackjackOperation::CancelRequest");
+                match self.state.user_status.get() {
+                    UserStatus::FindPlayChain => {
+                        self.state.user_status.set(UserStatus::Idle);
+                        self.state.find_play_chain_retry.set(0);
+                    }
+                    UserStatus::RequestingTableSeat if *self.state.on_waitlist.get() => {
+                        self.bankroll_release_escrow();
+                        self.state.user_status.set(UserStatus::Waitlisted);
+                    }
+                    UserStatus::RequestingTableSeat => {
+                        self.bankroll_release_escrow();
+                        self.state.user_status.set(UserStatus::PlayChainFound);
+                    }
+                    current_status => {
+                        panic!("no pending request to cancel, user status is {:?}", current_status);
+                    }
+                }
+                // Any late reply carries the cancelled id and is ignored
+                self.state.pending_request.set(None);
+                log::info!("Pending request cancelled, user status: {:?}", self.state.user_status.get());
+            }
             BlackjackOperation::GetBalance {} => {
                 log::info!("\n\nBlackjackOperation::GetBalance");
                 let balance = self.bankroll_get_balance();
                 log::info!("Current Balance is {:?}", balance);
             }
-            BlackjackOperation::Bet { amount } => {
-                log::info!("\n\nBlackjackOperation::Bet amount: {}", amount);
+            BlackjackOperation::ClaimBonus {} => {
+                log::info!("\n\nBlackjackOperation::ClaimBonus");
                 match self.state.user_status.get() {
-                    UserStatus::InMultiPlayerGame => {
-                        let game_status = &self.state.event_game_state.get().status;
-                        match game_status {
-                            BlackjackStatus::WaitingForPlayer | BlackjackStatus::PlayerTurn | BlackjackStatus::DealerTurn => {
-                                panic!("game in play, not ready for placing bets, please wait for the next hands");
-                            }
-                            BlackjackStatus::RoundEnded => {
-                                // TODO: prepare necessary thing before starting the next round if any
-                            }
-                            _ => {}
-                        }
-
-                        log::info!("Bet MultiPlayerGame, amount: {}", amount);
-                        self.multi_player_player_bet(amount).await;
+                    UserStatus::InMultiPlayerGame | UserStatus::InSinglePlayerGame => {
+                        panic!("user in game, please exit the game before claiming bonus");
                     }
-                    UserStatus::InSinglePlayerGame => {
-                        let game_status = &self.state.single_player_game.get().status;
-                        match game_status {
-                            BlackjackStatus::WaitingForPlayer | BlackjackStatus::PlayerTurn | BlackjackStatus::DealerTurn => {
-                                panic!("game in play, not ready for placing bets, please wait for the next hands");
-                            }
-                            BlackjackStatus::RoundEnded => {
-                                self.update_profile_balance_and_bet_data();
-                                self.prepare_next_single_player_bet_round().await;
-                            }
-                            _ => {}
-                        }
+                    _ => {}
+                }
 
-                        log::info!("Bet SinglePlayerGame, amount: {}", amount);
-                        self.player_bet(amount).await;
-                    }
-                    _ => {
-                        panic!("Player not in any Single or MultiPlayerGame!");
-                    }
+                let balance = self.bankroll_claim_bonus();
+                self.state.profile.get_mut().update_balance(balance);
+                self.calculate_profile_bet_data();
+                log::info!("Balance after claiming bonus is {:?}", balance);
+            }
+            BlackjackOperation::SetGamingLimits { limits } => {
+                log::info!("\n\nBlackjackOperation::SetGamingLimits limits: {:?}", limits);
+                let owner = self.runtime.application_id().into();
+                let bankroll_app_id = self.runtime.application_parameters().bankroll;
+                self.runtime
+                    .call_application(true, bankroll_app_id, &BankrollOperation::SetGamingLimits { owner, limits });
+            }
+            BlackjackOperation::SelfExclude { duration_micros } => {
+                log::info!("\n\nBlackjackOperation::SelfExclude duration_micros: {}", duration_micros);
+                let owner = self.runtime.application_id().into();
+                let bankroll_app_id = self.runtime.application_parameters().bankroll;
+                self.runtime
+                    .call_application(true, bankroll_app_id, &BankrollOperation::SelfExclude { owner, duration_micros });
+            }
+            BlackjackOperation::RetryDebt { debt_id } => {
+                log::info!("\n\nBlackjackOperation::RetryDebt debt_id: {}", debt_id);
+                // Route a rejected or partially paid debt away from the public chain it was last sent to
+                let target_chain = match self.bankroll_debt(debt_id) {
+                    Some(debt) if !debt.can_reroute() => debt.target_chain,
+                    debt => self.get_other_public_chain(debt.map(|debt| debt.target_chain)),
+                };
+                log::info!("Retrying debt {} on public chain: {:?}", debt_id, target_chain);
+                self.bankroll_retry_debt(debt_id, target_chain);
+            }
+            BlackjackOperation::Bet { amount } => {
+                log::info!("\n\nBlackjackOperation::Bet amount: {}", amount);
+                self.prepare_bet_round().await;
+                let chip_stack = self.current_bet_data().chip_stack_for(amount).unwrap_or_else(|error| panic!("{}", error));
+                self.commit_bet(amount, chip_stack).await;
+            }
+            BlackjackOperation::BetChips { chips } => {
+                log::info!("\n\nBlackjackOperation::BetChips chips: {:?}", chips);
+                self.prepare_bet_round().await;
+                let amount = self.chip_stack_amount(&chips);
+                self.commit_bet(amount, chips).await;
+            }
+            BlackjackOperation::RepeatBet {} => {
+                log::info!("\n\nBlackjackOperation::RepeatBet");
+                let amount = chip_stack_value(&self.state.profile.get().last_chip_stack);
+                if amount.is_zero() {
+                    panic!("no previous bet to repeat");
+                }
+                self.prepare_bet_round().await;
+                let chips = self.rebuild_chip_stack(amount);
+                self.commit_bet(amount, chips).await;
+            }
+            BlackjackOperation::DoubleBet {} => {
+                log::info!("\n\nBlackjackOperation::DoubleBet");
+                let amount = chip_stack_value(&double_chip_stack(&self.state.profile.get().last_chip_stack));
+                if amount.is_zero() {
+                    panic!("no previous bet to double");
+                }
+                self.prepare_bet_round().await;
+                let chips = self.rebuild_chip_stack(amount);
+                self.commit_bet(amount, chips).await;
+            }
+            BlackjackOperation::ClearBet {} => {
+                log::info!("\n\nBlackjackOperation::ClearBet");
+                self.prepare_bet_round().await;
+                match self.state.user_status.get() {
+                    UserStatus::InMultiPlayerGame => self.multi_player_player_bet(Amount::ZERO).await,
+                    _ => self.clear_single_player_bet(),
                 }
+                self.state.profile.get_mut().update_chip_stack(vec![]);
             }
             BlackjackOperation::DealBet {} => {
                 log::info!("\n\nBlackjackOperation::DealBet");
                 match self.state.user_status.get() {
-                    UserStatus::InMultiPlayerGame => {
-                        panic!("multi player deal bet not implemented yet");
-                    }
+                    UserStatus::InMultiPlayerGame => self.multi_player_deal_bet(),
                     UserStatus::InSinglePlayerGame => {
                         if self.state.single_player_game.get().status.ne(&BlackjackStatus::WaitingForBets) {
                             panic!("game in play, not ready for dealing bets, please wait for the next hands");
@@ -208,9 +291,7 @@ impl Contract for BlackjackContract {
             BlackjackOperation::Hit {} => {
                 log::info!("\n\nBlackjackOperation::Hit");
                 match self.state.user_status.get() {
-                    UserStatus::InMultiPlayerGame => {
-                        panic!("multi player hit not implemented yet");
-                    }
+                    UserStatus::InMultiPlayerGame => self.multi_player_action(PlayerAction::Hit),
                     UserStatus::InSinglePlayerGame => {
                         if self.state.single_player_game.get().status.ne(&BlackjackStatus::PlayerTurn) {
                             panic!("not the player turn");
@@ -247,9 +328,7 @@ impl Contract for BlackjackContract {
             BlackjackOperation::Stand {} => {
                 log::info!("\n\nBlackjackOperation::Stand");
                 match self.state.user_status.get() {
-                    UserStatus::InMultiPlayerGame => {
-                        panic!("multi player stand not implemented yet");
-                    }
+                    UserStatus::InMultiPlayerGame => self.multi_player_action(PlayerAction::Stand),
                     UserStatus::InSinglePlayerGame => {
                         if self.state.single_player_game.get().status.ne(&BlackjackStatus::PlayerTurn) {
                             panic!("not the player turn");
@@ -283,6 +362,8 @@ impl Contract for BlackjackContract {
                 log::info!("\n\nBlackjackOperation::StartSinglePlayerGame");
                 match self.state.user_status.get() {
                     UserStatus::Idle | UserStatus::PlayChainUnavailable => {
+                        let stakes = self.runtime.application_parameters().single_player_stakes;
+                        self.state.table_stakes.set(stakes);
                         self.update_profile_balance_and_bet_data();
                         self.add_user_to_new_single_player_game();
                         let token_pool_address = self.get_public_chain();
@@ -313,13 +394,207 @@ impl Contract for BlackjackContract {
                 }
 
                 self.update_profile_balance_and_bet_data();
+                self.bankroll_end_session();
+                self.state.table_stakes.set(None);
                 self.state.user_status.set(UserStatus::Idle);
                 self.state.single_player_game.clear();
                 self.state.player_seat_map.clear();
 
                 log::info!("Successfully exited single player game");
             }
+            BlackjackOperation::LeaveTable {} => {
+                log::info!("\n\nBlackjackOperation::LeaveTable");
+                let current_user_status = self.state.user_status.get();
+                log::info!("Current user status: {:?}", current_user_status);
+                if current_user_status.ne(&UserStatus::InMultiPlayerGame) {
+                    panic!("Player not in any MultiPlayerGame!");
+                }
+
+                let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found");
+                let seat_id = self.state.profile.get().seat.expect("missing Player Seat ID");
+                if self.state.event_game_state.get().is_hand_in_play() {
+                    // The Play Chain decides what is forfeited, the escrow waits for SeatReleased
+                    log::info!("Leaving mid-hand, live bet on seat {} will be forfeited", seat_id);
+                    self.state.pending_leave.set(Some(play_chain_id));
+                }
+
+                self.message_manager(play_chain_id, BlackjackMessage::LeaveTable { seat_id });
+                self.message_manager(play_chain_id, BlackjackMessage::Unsubscribe);
+                log::info!("Sent LeaveTable and Unsubscribe message to play_chain: {:?}", play_chain_id);
+
+                self.reset_multi_player_session();
+                log::info!("Successfully left table seat {} on play_chain: {:?}", seat_id, play_chain_id);
+            }
+            BlackjackOperation::ResyncTable {} => {
+                log::info!("\n\nBlackjackOperation::ResyncTable");
+                let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found, please call FindPlayChain first");
+
+                // Subscribing again is a no-op when still subscribed, and restores events after a restart
+                self.message_manager(play_chain_id, BlackjackMessage::Subscribe);
+                self.request_table_resync(play_chain_id);
+            }
+            BlackjackOperation::Spectate { chain_id } => {
+                log::info!("\n\nBlackjackOperation::Spectate chain_id: {:?}", chain_id);
+                match self.state.user_status.get() {
+                    UserStatus::Idle | UserStatus::PlayChainUnavailable => {}
+                    UserStatus::Spectating => {
+                        let spectated_chain = self.state.user_play_chain.get().expect("no spectated Play Chain found");
+                        self.message_manager(spectated_chain, BlackjackMessage::StopSpectating);
+                    }
+                    current_status => {
+                        panic!("Unable to Spectate, user status is {:?}", current_status);
+                    }
+                }
+
+                // Spectating never takes a seat, player_seat_map stays untouched
+                self.reset_spectating_session();
+                self.state.user_status.set(UserStatus::Spectating);
+                self.state.user_play_chain.set(Some(chain_id));
+                self.message_manager(chain_id, BlackjackMessage::Spectate);
+                log::info!("Sent Spectate message to play_chain: {:?}", chain_id);
+            }
+            BlackjackOperation::StopSpectating {} => {
+                log::info!("\n\nBlackjackOperation::StopSpectating");
+                if self.state.user_status.get().ne(&UserStatus::Spectating) {
+                    panic!("user is not spectating any table");
+                }
+
+                let spectated_chain = self.state.user_play_chain.get().expect("no spectated Play Chain found");
+                self.message_manager(spectated_chain, BlackjackMessage::StopSpectating);
+                self.reset_spectating_session();
+                log::info!("Stopped spectating play_chain: {:?}", spectated_chain);
+            }
+            BlackjackOperation::SendChatMessage { text } => {
+                log::info!("\n\nBlackjackOperation::SendChatMessage");
+                let text = sanitize_chat_text(&text).unwrap_or_else(|| panic!("chat message must be 1-{} characters", MAX_CHAT_MESSAGE_LENGTH));
+                let play_chain_id = self.chat_play_chain();
+                self.message_manager(play_chain_id, BlackjackMessage::ChatMessage { text });
+            }
+            BlackjackOperation::SendEmote { emote } => {
+                log::info!("\n\nBlackjackOperation::SendEmote emote: {:?}", emote);
+                let play_chain_id = self.chat_play_chain();
+                self.message_manager(play_chain_id, BlackjackMessage::ChatEmote { emote });
+            }
+            BlackjackOperation::ReservePrivateTable { play_chain_id, invite } => {
+                log::info!("\n\nBlackjackOperation::ReservePrivateTable play_chain_id: {:?}", play_chain_id);
+                if let Err(error) = validate_invite_code(&invite.invite_code) {
+                    panic!("{}", error);
+                }
+                let is_master_chain = self.runtime.chain_id() == self.runtime.application_parameters().master_chain;
+                let mut private_tables = self.state.private_tables.get().clone();
+                if !is_master_chain && !private_tables.contains(&play_chain_id) {
+                    if private_tables.len() >= MAX_PRIVATE_TABLES_PER_OWNER {
+                        panic!("at most {} private tables per owner, please release one first", MAX_PRIVATE_TABLES_PER_OWNER);
+                    }
+                    private_tables.push(play_chain_id);
+                    self.state.private_tables.set(private_tables);
+                }
+
+                // Only the hash leaves this chain, the code is shared out of band
+                let table = PrivateTable {
+                    owner: self.runtime.chain_id(),
+                    invite_code_hash: hash_invite_code(play_chain_id, &invite.invite_code),
+                    allowlist: invite.allowlist,
+                };
+                self.message_manager(play_chain_id, BlackjackMessage::ReservePrivateTable { table });
+            }
+            BlackjackOperation::ReleasePrivateTable { play_chain_id } => {
+                log::info!("\n\nBlackjackOperation::ReleasePrivateTable play_chain_id: {:?}", play_chain_id);
+                self.message_manager(play_chain_id, BlackjackMessage::ReleasePrivateTable);
+            }
+            BlackjackOperation::EnterPrivateTable { chain_id, invite_code } => {
+                log::info!("\n\nBlackjackOperation::EnterPrivateTable chain_id: {:?}", chain_id);
+                self.release_found_play_chain();
+
+                self.state.user_status.set(UserStatus::FindPlayChain);
+                self.state.find_play_chain_retry.set(0);
+                let request_id = self.start_request();
+                self.send_request(chain_id, BlackjackMessage::EnterPrivateTable { invite_code, request_id });
+                log::info!("Sent EnterPrivateTable message to play_chain: {:?}", chain_id);
+            }
+            BlackjackOperation::JoinWaitlist { seat_id } => {
+                log::info!("\n\nBlackjackOperation::JoinWaitlist seat_id: {:?}", seat_id);
+                if seat_id.is_some_and(|seat_id| seat_id == 0 || seat_id > MAX_BLACKJACK_PLAYERS as u8) {
+                    panic!("seat_id is invalid, can only be 1-{:?}", MAX_BLACKJACK_PLAYERS);
+                }
+                match self.state.user_status.get() {
+                    UserStatus::PlayChainFound | UserStatus::RequestTableSeatFail => {}
+                    UserStatus::Waitlisted => {
+                        panic!("user is already on the waiting list");
+                    }
+                    current_status => {
+                        panic!("Unable to JoinWaitlist, user status is {:?}", current_status);
+                    }
+                }
+
+                let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found, please call FindPlayChain first");
+                self.message_manager(play_chain_id, BlackjackMessage::JoinWaitlist { seat_id });
+                self.state.user_status.set(UserStatus::Waitlisted);
+                self.state.on_waitlist.set(true);
+                self.state.seat_offer.set(None);
+                log::info!("Sent JoinWaitlist message to play_chain: {:?}", play_chain_id);
+            }
+            BlackjackOperation::LeaveWaitlist {} => {
+                log::info!("\n\nBlackjackOperation::LeaveWaitlist");
+                if self.state.user_status.get().ne(&UserStatus::Waitlisted) {
+                    panic!("user is not on any waiting list");
+                }
+
+                let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found");
+                self.message_manager(play_chain_id, BlackjackMessage::LeaveWaitlist);
+                self.reset_waitlist_session();
+                log::info!("Left the waiting list of play_chain: {:?}", play_chain_id);
+            }
+            BlackjackOperation::MuteChat { chain_id } => {
+                log::info!("\n\nBlackjackOperation::MuteChat chain_id: {:?}", chain_id);
+                let current_time = self.runtime.system_time();
+                self.state.chat_muted.insert(&chain_id, current_time).unwrap_or_else(|_| {
+                    panic!("Failed to mute chat from {:?}", chain_id);
+                });
+            }
+            BlackjackOperation::UnmuteChat { chain_id } => {
+                log::info!("\n\nBlackjackOperation::UnmuteChat chain_id: {:?}", chain_id);
+                self.state.chat_muted.remove(&chain_id).unwrap_or_else(|_| {
+                    panic!("Failed to unmute chat from {:?}", chain_id);
+                });
+            }
+            // * Play Chain
+            BlackjackOperation::Heartbeat {} => {
+                log::info!("\n\nBlackjackOperation::Heartbeat");
+                self.expire_table_time_limit();
+                self.settle_dealer_turn().await;
+                self.release_idle_private_table();
+                self.offer_free_seats().await;
+                self.report_play_chain_heartbeat();
+            }
             // * Master Chain
+            BlackjackOperation::RegisterPublicChain { chain_id } => {
+                log::info!("\n\nBlackjackOperation::RegisterPublicChain chain_id: {:?}", chain_id);
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackOperation::RegisterPublicChain"
+                );
+                self.public_chain_registry_manager(chain_id, Some(PublicChainStatus::Live)).await;
+            }
+            BlackjackOperation::DeregisterPublicChain { chain_id } => {
+                log::info!("\n\nBlackjackOperation::DeregisterPublicChain chain_id: {:?}", chain_id);
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackOperation::DeregisterPublicChain"
+                );
+                self.public_chain_registry_manager(chain_id, None).await;
+            }
+            BlackjackOperation::DrainPublicChain { chain_id } => {
+                log::info!("\n\nBlackjackOperation::DrainPublicChain chain_id: {:?}", chain_id);
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackOperation::DrainPublicChain"
+                );
+                self.public_chain_registry_manager(chain_id, Some(PublicChainStatus::Draining)).await;
+            }
             BlackjackOperation::AddPlayChain {
                 target_public_chain,
                 play_chain_id,
@@ -336,9 +611,55 @@ impl Contract for BlackjackContract {
                     target_public_chain,
                     play_chain_id
                 );
+                if self.public_chain_registry_status(target_public_chain).await != Some(PublicChainStatus::Live) {
+                    panic!("public chain {:?} is not live", target_public_chain);
+                }
                 self.message_manager(target_public_chain, BlackjackMessage::AddPlayChain { chain_id: play_chain_id });
                 log::info!("Sent AddPlayChain message to target_public_chain: {:?}", target_public_chain);
             }
+            BlackjackOperation::SetPlayChainMode { play_chain_id, mode } => {
+                log::info!("\n\nBlackjackOperation::SetPlayChainMode");
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackOperation::SetPlayChainMode"
+                );
+                log::info!("Setting mode {:?} on play_chain_id: {:?}", mode, play_chain_id);
+                self.message_manager(play_chain_id, BlackjackMessage::SetPlayChainMode { mode });
+            }
+            BlackjackOperation::RemovePlayChain { play_chain_id } => {
+                log::info!("\n\nBlackjackOperation::RemovePlayChain");
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackOperation::RemovePlayChain"
+                );
+                log::info!("Closing play_chain_id: {:?}", play_chain_id);
+                self.message_manager(play_chain_id, BlackjackMessage::ClosePlayChain);
+            }
+            BlackjackOperation::SetTableStakes { play_chain_id, stakes } => {
+                log::info!("\n\nBlackjackOperation::SetTableStakes");
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackOperation::SetTableStakes"
+                );
+                if let Err(error) = stakes.validate() {
+                    panic!("invalid table stakes: {}", error);
+                }
+                log::info!("Setting table stakes {:?} on play_chain_id: {:?}", stakes, play_chain_id);
+                self.message_manager(play_chain_id, BlackjackMessage::SetTableStakes { stakes });
+            }
+            BlackjackOperation::SetTableRules { play_chain_id, rules } => {
+                log::info!("\n\nBlackjackOperation::SetTableRules");
+                assert_eq!(
+                    self.runtime.chain_id(),
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackOperation::SetTableRules"
+                );
+                log::info!("Setting table rules {:?} on play_chain_id: {:?}", rules, play_chain_id);
+                self.message_manager(play_chain_id, BlackjackMessage::SetTableRules { rules });
+            }
             BlackjackOperation::MintToken { chain_id, amount } => {
                 log::info!("\n\nBlackjackOperation::MintToken");
                 assert_eq!(
@@ -362,34 +683,250 @@ impl Contract for BlackjackContract {
 
     async fn execute_message(&mut self, message: Self::Message) {
         let origin_chain_id = self.runtime.message_origin_chain_id().expect("Chain ID missing from message");
+        let Some(message) = message.into_current() else {
+            log::info!("Skipping message from {:?} that does not decode with this schema", origin_chain_id);
+            return;
+        };
+
+        // A late reply is handled before its request would be sent again
+        if !matches!(
+            message,
+            BlackjackMessage::FindPlayChainResult { .. } | BlackjackMessage::RequestTableSeatResult { .. } | BlackjackMessage::PrivateTableResult { .. }
+        ) {
+            self.resend_expired_request();
+        }
+        self.offer_free_seats().await;
 
         match message {
             // * User Chain
-            BlackjackMessage::FindPlayChainResult { chain_id } => {
+            BlackjackMessage::PublicChains { chains } => {
+                log::info!("\n\nBlackjackMessage::PublicChains");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackMessage::PublicChains"
+                );
+                log::info!("Live public chains: {:?}", chains);
+                if chains.is_empty() {
+                    log::info!("Ignoring an empty public chain list, keeping {:?}", self.state.public_chains.get());
+                    return;
+                }
+                self.state.public_chains.set(Some(chains.clone()));
+
+                // Move the token pot away from a public chain that is no longer live
+                if let Some(token_pool_address) = *self.state.token_pool_address.get() {
+                    if !chains.is_empty() && !chains.contains(&token_pool_address) {
+                        let next_address = self.get_public_chain();
+                        log::info!("Token pool address moved from {:?} to {:?}", token_pool_address, next_address);
+                        self.state.token_pool_address.set(Some(next_address));
+                    }
+                }
+            }
+            BlackjackMessage::FindPlayChainResult { chain_id, request_id } => {
                 log::info!("\n\nBlackjackMessage::FindPlayChainResult");
                 log::info!("BlackjackMessage::FindPlayChainResult from {:?}, chain_id: {:?}", origin_chain_id, chain_id);
+                if !self.is_current_request(request_id, UserStatus::FindPlayChain) {
+                    log::info!("Ignoring stale FindPlayChainResult for request {}", request_id);
+                    return;
+                }
                 if self.process_find_play_chain_result(origin_chain_id, chain_id) {
                     log::info!("Play chain found successfully, updating profile balance and bet data");
                     self.update_profile_balance_and_bet_data();
                 }
             }
-            BlackjackMessage::RequestTableSeatResult { seat_id, success } => {
+            BlackjackMessage::PrivateTableResult { accepted, request_id } => {
+                log::info!("\n\nBlackjackMessage::PrivateTableResult");
+                if !self.is_current_request(request_id, UserStatus::FindPlayChain) {
+                    log::info!("Ignoring stale PrivateTableResult for request {}", request_id);
+                    return;
+                }
+
+                if accepted && self.process_find_play_chain_result(origin_chain_id, Some(origin_chain_id)) {
+                    log::info!("Private table {:?} entered, updating profile balance and bet data", origin_chain_id);
+                    self.update_profile_balance_and_bet_data();
+                    return;
+                }
+                log::info!("Private table {:?} refused the invite code", origin_chain_id);
+                self.state.user_status.set(UserStatus::PlayChainUnavailable);
+                self.state.pending_request.set(None);
+            }
+            BlackjackMessage::RequestTableSeatResult { request_id, result } => {
                 log::info!("\n\nBlackjackMessage::RequestTableSeatResult");
-                if success {
-                    self.add_user_to_new_multi_player_game(seat_id);
+                if !self.is_current_request(request_id, UserStatus::RequestingTableSeat) {
+                    log::info!("Ignoring stale RequestTableSeatResult for request {}", request_id);
+                    if result.success {
+                        // Give back the seat that was granted too late, the seat also cost the place in line
+                        self.message_manager(origin_chain_id, BlackjackMessage::LeaveTable { seat_id: result.seat_id });
+                        if self.state.user_play_chain.get().eq(&Some(origin_chain_id)) {
+                            self.reset_waitlist_session();
+                        }
+                    }
+                    return;
+                }
+
+                self.state.pending_request.set(None);
+                if result.success {
+                    self.state.on_waitlist.set(false);
+                    self.state.seat_offer.set(None);
+                    // The seat stack is the escrowed buy-in, reconciled with the Play Chain before the first bet
+                    let escrow = self.bound_seat_escrow(result.stakes.as_ref());
+                    self.state.profile.get_mut().update_balance(escrow);
+                    self.state.table_stakes.set(result.stakes);
+                    self.calculate_profile_bet_data();
+                    self.add_user_to_new_multi_player_game(result.seat_id);
                     log::info!("RequestTableSeatResult SUCCESS on {:?}!", origin_chain_id);
                     return;
                 }
-                self.state.user_status.set(UserStatus::RequestTableSeatFail);
+                self.bankroll_release_escrow();
+                if *self.state.on_waitlist.get() {
+                    // Still in line, a SeatAvailable will follow when a seat frees up
+                    self.state.user_status.set(UserStatus::Waitlisted);
+                } else {
+                    self.state.user_status.set(UserStatus::RequestTableSeatFail);
+                }
                 log::info!("RequestTableSeatResult FAILED on {:?}", origin_chain_id);
             }
+            BlackjackMessage::TableClosed => {
+                log::info!("\n\nBlackjackMessage::TableClosed");
+                if self.state.user_play_chain.get().ne(&Some(origin_chain_id)) {
+                    log::info!("Ignoring TableClosed from {:?}, not the current Play Chain", origin_chain_id);
+                    return;
+                }
+
+                self.message_manager(origin_chain_id, BlackjackMessage::Unsubscribe);
+                match self.state.user_status.get() {
+                    UserStatus::InMultiPlayerGame => self.reset_multi_player_session(),
+                    UserStatus::Spectating => self.reset_spectating_session(),
+                    _ => {
+                        // A seat request in flight still holds the buy-in
+                        self.bankroll_release_escrow();
+                        self.reset_waitlist_session();
+                        self.state.user_status.set(UserStatus::Idle);
+                        self.state.user_play_chain.set(None);
+                    }
+                }
+                log::info!("Play Chain {:?} closed, please FindPlayChain for another table", origin_chain_id);
+            }
+            BlackjackMessage::PrivateTableReservation { reserved } => {
+                log::info!("\n\nBlackjackMessage::PrivateTableReservation");
+                let mut private_tables = self.state.private_tables.get().clone();
+                if reserved {
+                    log::info!("Play Chain {:?} reserved as private table", origin_chain_id);
+                    return;
+                }
+                private_tables.retain(|chain_id| *chain_id != origin_chain_id);
+                self.state.private_tables.set(private_tables);
+                log::info!("Private table {:?} rejected or released", origin_chain_id);
+            }
+            BlackjackMessage::TableModeChanged { mode } => {
+                log::info!("\n\nBlackjackMessage::TableModeChanged");
+                if self.state.user_play_chain.get().ne(&Some(origin_chain_id)) {
+                    log::info!("Ignoring TableModeChanged from {:?}, not the current Play Chain", origin_chain_id);
+                    return;
+                }
+                match mode {
+                    PlayChainMode::Paused => log::info!("Play Chain {:?} paused, no new hands until it resumes", origin_chain_id),
+                    PlayChainMode::Draining => log::info!("Play Chain {:?} closes after the current round", origin_chain_id),
+                    _ => log::info!("Play Chain {:?} is now {:?}", origin_chain_id, mode),
+                }
+                self.state.table_mode.set(Some(mode));
+            }
+            BlackjackMessage::TableSnapshot { game } => {
+                log::info!("\n\nBlackjackMessage::TableSnapshot");
+                if self.state.user_play_chain.get().ne(&Some(origin_chain_id)) {
+                    log::info!("Ignoring TableSnapshot from {:?}, not the current Play Chain", origin_chain_id);
+                    return;
+                }
+
+                self.state.table_resync_pending.set(false);
+                let current_sequence = self.state.event_game_state.get().sequence;
+                if game.sequence < current_sequence {
+                    log::info!(
+                        "TableSnapshot sequence {} is older than {}, keeping current state",
+                        game.sequence,
+                        current_sequence
+                    );
+                    return;
+                }
+                log::info!("Table resynced from {:?} at sequence {}", origin_chain_id, game.sequence);
+                self.state.event_game_state.set(game);
+            }
+            BlackjackMessage::WaitlistJoined { position } => {
+                log::info!("\n\nBlackjackMessage::WaitlistJoined");
+                if self.state.user_play_chain.get().ne(&Some(origin_chain_id)) || !*self.state.on_waitlist.get() {
+                    log::info!("Ignoring WaitlistJoined from {:?}, not waiting on it", origin_chain_id);
+                    return;
+                }
+
+                match position {
+                    Some(position) => log::info!("Waiting for a seat on {:?} at position {}", origin_chain_id, position),
+                    None => {
+                        log::info!("Not on the waiting list of {:?}", origin_chain_id);
+                        self.reset_waitlist_session();
+                    }
+                }
+            }
+            BlackjackMessage::SeatAvailable { offer } => {
+                log::info!("\n\nBlackjackMessage::SeatAvailable");
+                if self.state.user_play_chain.get().ne(&Some(origin_chain_id)) || !*self.state.on_waitlist.get() {
+                    log::info!("Ignoring SeatAvailable from {:?}, not waiting on it", origin_chain_id);
+                    return;
+                }
+
+                // The user claims it with RequestTableSeat before the deadline
+                log::info!("Seat {} offered on {:?} until {:?}", offer.seat_id, origin_chain_id, offer.deadline);
+                self.state.seat_offer.set(Some(offer));
+            }
+            BlackjackMessage::RoundResult { settlement } => {
+                log::info!("\n\nBlackjackMessage::RoundResult");
+                // Only the table itself can send its results, the message origin is authenticated by the runtime
+                if self.state.user_play_chain.get().ne(&Some(origin_chain_id)) || settlement.play_chain_id != origin_chain_id {
+                    log::info!("Ignoring RoundResult from {:?}, not the current Play Chain", origin_chain_id);
+                    return;
+                }
+                self.receive_round_settlement(settlement).await;
+            }
+            BlackjackMessage::SeatReleased { seat_id, forfeited } => {
+                log::info!("\n\nBlackjackMessage::SeatReleased");
+                log::info!("Seat {} released by {:?}, forfeited bet: {}", seat_id, origin_chain_id, forfeited);
+                if self.state.pending_leave.get().eq(&Some(origin_chain_id)) {
+                    self.state.pending_leave.set(None);
+                    self.forfeit_seat_bet(forfeited);
+                    self.bankroll_release_escrow();
+                    self.update_profile_balance_and_bet_data();
+                } else if self.state.user_play_chain.get().ne(&Some(origin_chain_id)) {
+                    log::info!("Ignoring SeatReleased from {:?}, not the current Play Chain", origin_chain_id);
+                } else if !forfeited.is_zero() {
+                    // Left while the hand looked over from here, the escrow was already released
+                    let balance = self.bankroll_get_balance();
+                    self.bankroll_update_balance(balance.saturating_sub(forfeited));
+                    let public_chain = self.settlement_public_chain(None);
+                    self.bankroll_transfer_token_pot(forfeited, public_chain);
+                    self.update_profile_balance_and_bet_data();
+                }
+            }
             // * Public Chain
-            BlackjackMessage::FindPlayChain => {
+            BlackjackMessage::SetPublicChainDraining { draining } => {
+                log::info!("\n\nBlackjackMessage::SetPublicChainDraining");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackMessage::SetPublicChainDraining"
+                );
+                log::info!("Public chain {:?} draining: {}", self.runtime.chain_id(), draining);
+                self.state.public_chain_draining.set(draining);
+            }
+            BlackjackMessage::FindPlayChain { filter, request_id } => {
                 log::info!("\n\nBlackjackMessage::FindPlayChain");
                 log::info!("FindPlayChain Request Accepted at {:?} from: {:?}", self.runtime.chain_id(), origin_chain_id);
 
-                let result = self.search_available_play_chain().await;
-                self.message_manager(origin_chain_id, BlackjackMessage::FindPlayChainResult { chain_id: result });
+                let result = if *self.state.public_chain_draining.get() {
+                    log::info!("Public chain is draining, no Play Chain offered");
+                    None
+                } else {
+                    self.search_available_play_chain(&filter).await
+                };
+                self.message_manager(origin_chain_id, BlackjackMessage::FindPlayChainResult { chain_id: result, request_id });
             }
             BlackjackMessage::AddPlayChain { chain_id } => {
                 log::info!("\n\nBlackjackMessage::AddPlayChain");
@@ -400,42 +937,415 @@ impl Contract for BlackjackContract {
                 );
                 log::info!("BankrollMessage::AddPlayChain from {:?} at {:?}", origin_chain_id, self.runtime.chain_id());
                 self.play_chain_manager(chain_id, 0, MutationReason::AddNew).await;
+                // The Master Chain checks its registry before the Play Chain accepts the listing
+                let master_chain = self.runtime.application_parameters().master_chain;
+                self.message_manager(master_chain, BlackjackMessage::PlayChainListed { chain_id });
+            }
+            BlackjackMessage::PlayChainHeartbeat { heartbeat } => {
+                log::info!("\n\nBlackjackMessage::PlayChainHeartbeat");
+                let Some(current_number) = self.state.play_chain_status.get(&origin_chain_id).await.unwrap_or_default() else {
+                    log::info!("Ignoring heartbeat from unknown Play Chain {:?}", origin_chain_id);
+                    return;
+                };
+                log::info!("Play Chain {:?} heartbeat: {:?}", origin_chain_id, heartbeat);
+
+                // A full table sits in the MAX_BLACKJACK_PLAYERS bucket, which is never searched
+                let player_number = heartbeat.player_number.min(MAX_BLACKJACK_PLAYERS as u8);
+                let current_time = self.runtime.system_time();
+                let health = PlayChainHealth::new(origin_chain_id, heartbeat, current_time);
+                self.state.play_chain_health.insert(&origin_chain_id, health).unwrap_or_else(|_| {
+                    panic!("Failed to update Play Chain Health for {:?}", origin_chain_id);
+                });
+
+                if player_number != current_number {
+                    log::info!("Play Chain {:?} now has {} player(s)", origin_chain_id, player_number);
+                    self.play_chain_manager(origin_chain_id, player_number, MutationReason::Update).await;
+                }
+            }
+            BlackjackMessage::PlayChainListing { stakes, rules } => {
+                log::info!("\n\nBlackjackMessage::PlayChainListing");
+                if !self.state.play_chain_status.contains_key(&origin_chain_id).await.unwrap_or(false) {
+                    log::info!("Ignoring listing from unknown Play Chain {:?}", origin_chain_id);
+                    return;
+                }
+                let mut listing = self
+                    .state
+                    .play_chain_listing
+                    .get(&origin_chain_id)
+                    .await
+                    .unwrap_or_default()
+                    .unwrap_or_default();
+                log::info!("Play Chain {:?} listed with stakes: {:?}, rules: {:?}", origin_chain_id, stakes, rules);
+                listing.stakes = stakes;
+                listing.rules = rules;
+                self.state.play_chain_listing.insert(&origin_chain_id, listing).unwrap_or_else(|_| {
+                    panic!("Failed to update Play Chain Listing for {:?}", origin_chain_id);
+                });
+            }
+            BlackjackMessage::PlayChainModeUpdate { mode } => {
+                log::info!("\n\nBlackjackMessage::PlayChainModeUpdate");
+                if !self.state.play_chain_status.contains_key(&origin_chain_id).await.unwrap_or(false) {
+                    log::info!("Ignoring mode from unknown Play Chain {:?}", origin_chain_id);
+                    return;
+                }
+                log::info!("Play Chain {:?} mode is now {:?}", origin_chain_id, mode);
+                let mut listing = self
+                    .state
+                    .play_chain_listing
+                    .get(&origin_chain_id)
+                    .await
+                    .unwrap_or_default()
+                    .unwrap_or_default();
+                listing.mode = mode;
+                self.state.play_chain_listing.insert(&origin_chain_id, listing).unwrap_or_else(|_| {
+                    panic!("Failed to update Play Chain Listing for {:?}", origin_chain_id);
+                });
+            }
+            BlackjackMessage::RemovePlayChain => {
+                log::info!("\n\nBlackjackMessage::RemovePlayChain");
+                if !self.state.play_chain_status.contains_key(&origin_chain_id).await.unwrap_or(false) {
+                    log::info!("Ignoring removal of unknown Play Chain {:?}", origin_chain_id);
+                    return;
+                }
+                self.play_chain_manager(origin_chain_id, 0, MutationReason::Remove).await;
+                log::info!("Play Chain {:?} removed from {:?}", origin_chain_id, self.runtime.chain_id());
             }
             // * Play Chain
             BlackjackMessage::Subscribe => {
                 log::info!("\n\nBlackjackMessage::Subscribe");
                 let app_id = self.runtime.application_id().forget_abi();
                 self.runtime.subscribe_to_events(origin_chain_id, app_id, BLACKJACK_STREAM_NAME.into());
+                self.runtime.subscribe_to_events(origin_chain_id, app_id, CHAT_STREAM_NAME.into());
                 log::info!("User {:?} subscribe to Play Chain {:?}", origin_chain_id, self.runtime.chain_id());
+
+                // Table events are deltas, a new subscriber starts from the current snapshot
+                let game = self.state.game.get().data_for_event();
+                self.message_manager(origin_chain_id, BlackjackMessage::TableSnapshot { game });
             }
             BlackjackMessage::Unsubscribe => {
                 log::info!("\n\nBlackjackMessage::Unsubscribe");
                 let app_id = self.runtime.application_id().forget_abi();
                 self.runtime.unsubscribe_from_events(origin_chain_id, app_id, BLACKJACK_STREAM_NAME.into());
+                self.runtime.unsubscribe_from_events(origin_chain_id, app_id, CHAT_STREAM_NAME.into());
                 log::info!("User {:?} unsubscribe from Play Chain {:?}", origin_chain_id, self.runtime.chain_id());
             }
-            BlackjackMessage::RequestTableSeat { seat_id, balance } => {
-                log::info!("\n\nBlackjackMessage::RequestTableSeat");
-                if self.request_table_seat_manager(seat_id, balance, origin_chain_id).is_some() {
-                    let game = self.state.game.get();
-                    self.event_manager(BlackjackEvent::GameState { game: game.data_for_event() })
+            BlackjackMessage::SetTableStakes { stakes } => {
+                log::info!("\n\nBlackjackMessage::SetTableStakes");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackMessage::SetTableStakes"
+                );
+                log::info!("Table stakes on Play Chain {:?} set to {:?}", self.runtime.chain_id(), stakes);
+                self.state.play_chain_stakes.set(Some(stakes));
+                self.report_play_chain_listing();
+            }
+            BlackjackMessage::SetTableRules { rules } => {
+                log::info!("\n\nBlackjackMessage::SetTableRules");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackMessage::SetTableRules"
+                );
+                if self.state.game.get().is_hand_in_play() {
+                    panic!("table rules can't change during a hand");
                 }
-                log::info!("User {:?} RequestTableSeat to Play Chain {:?}", origin_chain_id, self.runtime.chain_id());
+                log::info!("Table rules on Play Chain {:?} set to {:?}", self.runtime.chain_id(), rules);
+                self.state.play_chain_rules.set(rules);
+                self.report_play_chain_listing();
             }
-        }
-    }
+            BlackjackMessage::SetPlayChainMode { mode } => {
+                log::info!("\n\nBlackjackMessage::SetPlayChainMode");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackMessage::SetPlayChainMode"
+                );
+                assert_ne!(mode, PlayChainMode::Private, "Private mode is set through ReservePrivateTable");
+                log::info!("Play Chain {:?} mode set to {:?}", self.runtime.chain_id(), mode);
+                self.set_play_chain_mode(mode);
+                self.close_drained_play_chain().await;
+                self.offer_free_seats().await;
+            }
+            BlackjackMessage::ReservePrivateTable { table } => {
+                log::info!("\n\nBlackjackMessage::ReservePrivateTable");
+                let is_master_chain = origin_chain_id == self.runtime.application_parameters().master_chain;
+                let mode = *self.state.play_chain_mode.get();
+                let is_free = mode == PlayChainMode::Open && self.state.game.get().players.is_empty();
+                let previous_owner = self.state.private_table.get().as_ref().map(|table| table.owner);
+
+                // Users can only reserve an empty public table or renew their own, the Master Chain can reserve any
+                if !is_master_chain && previous_owner != Some(origin_chain_id) && (!is_free || previous_owner.is_some()) {
+                    log::info!(
+                        "Play Chain {:?} is not free, rejecting reservation from {:?}",
+                        self.runtime.chain_id(),
+                        origin_chain_id
+                    );
+                    self.message_manager(origin_chain_id, BlackjackMessage::PrivateTableReservation { reserved: false });
+                    return;
+                }
+                if let Some(previous_owner) = previous_owner.filter(|owner| *owner != origin_chain_id) {
+                    self.message_manager(previous_owner, BlackjackMessage::PrivateTableReservation { reserved: false });
+                }
+                let table = PrivateTable {
+                    owner: origin_chain_id,
+                    ..table
+                };
+                log::info!("Play Chain {:?} reserved as private table by {:?}", self.runtime.chain_id(), origin_chain_id);
+                self.state.private_table.set(Some(table));
+                self.state.private_table_guests.clear();
+                self.state.private_table_active_at.set(self.runtime.system_time());
+                if mode != PlayChainMode::Private {
+                    self.set_play_chain_mode(PlayChainMode::Private);
+                }
+                self.message_manager(origin_chain_id, BlackjackMessage::PrivateTableReservation { reserved: true });
+            }
+            BlackjackMessage::ReleasePrivateTable => {
+                log::info!("\n\nBlackjackMessage::ReleasePrivateTable");
+                let Some(table) = self.state.private_table.get().clone() else {
+                    log::info!("Play Chain {:?} is not a private table", self.runtime.chain_id());
+                    return;
+                };
+                if origin_chain_id != table.owner && origin_chain_id != self.runtime.application_parameters().master_chain {
+                    log::info!(
+                        "{:?} does not own private table {:?}, ignoring release",
+                        origin_chain_id,
+                        self.runtime.chain_id()
+                    );
+                    return;
+                }
+                self.release_private_table(table.owner);
+                self.offer_free_seats().await;
+                log::info!("Private table {:?} released by {:?}", self.runtime.chain_id(), origin_chain_id);
+            }
+            BlackjackMessage::EnterPrivateTable { invite_code, request_id } => {
+                log::info!("\n\nBlackjackMessage::EnterPrivateTable");
+                let play_chain_id = self.runtime.chain_id();
+                let accepted = self
+                    .state
+                    .private_table
+                    .get()
+                    .as_ref()
+                    .is_some_and(|table| table.is_valid_code(play_chain_id, &invite_code) && table.is_allowed(origin_chain_id));
+                if accepted {
+                    let current_time = self.runtime.system_time();
+                    self.state.private_table_guests.insert(&origin_chain_id, current_time).unwrap_or_else(|_| {
+                        panic!("Failed to add private table guest {:?}", origin_chain_id);
+                    });
+                    self.state.private_table_active_at.set(current_time);
+                }
+                log::info!("EnterPrivateTable from {:?} accepted: {}", origin_chain_id, accepted);
+                self.message_manager(origin_chain_id, BlackjackMessage::PrivateTableResult { accepted, request_id });
+            }
+            BlackjackMessage::JoinWaitlist { seat_id } => {
+                log::info!("\n\nBlackjackMessage::JoinWaitlist");
+                let position = self.join_waitlist_manager(origin_chain_id, seat_id).await;
+                log::info!("User {:?} JoinWaitlist for seat {:?}, position: {:?}", origin_chain_id, seat_id, position);
+                self.message_manager(origin_chain_id, BlackjackMessage::WaitlistJoined { position });
+                self.offer_free_seats().await;
+            }
+            BlackjackMessage::LeaveWaitlist => {
+                log::info!("\n\nBlackjackMessage::LeaveWaitlist");
+                self.remove_from_waitlist(origin_chain_id).await;
+                self.offer_free_seats().await;
+                log::info!("User {:?} left the waiting list of {:?}", origin_chain_id, self.runtime.chain_id());
+            }
+            BlackjackMessage::SeatReconciled { seat_id, balance, round } => {
+                log::info!("\n\nBlackjackMessage::SeatReconciled");
+                self.seat_reconciled_manager(seat_id, balance, round, origin_chain_id);
+            }
+            BlackjackMessage::PlaceTableBet { seat_id, amount } => {
+                log::info!("\n\nBlackjackMessage::PlaceTableBet");
+                if self.place_table_bet_manager(seat_id, amount, origin_chain_id).await.is_some() {
+                    let sequence = self.next_table_sequence();
+                    self.emit_table_event(BlackjackEvent::BetPlaced { sequence, seat_id, amount });
+                }
+            }
+            BlackjackMessage::DealTable { seat_id } => {
+                log::info!("\n\nBlackjackMessage::DealTable");
+                let game = self.state.game.get();
+                if game.players.get(&seat_id).is_none_or(|player| player.chain_id != Some(origin_chain_id)) {
+                    log::info!("Seat {} is not held by {:?}, ignoring DealTable", seat_id, origin_chain_id);
+                    return;
+                }
+                if game.players.values().any(|player| player.bet.is_zero()) {
+                    log::info!("Seat {} is ready, waiting for the other seats to bet", seat_id);
+                    return;
+                }
+                if self.can_deal_table() {
+                    self.deal_multi_player_round();
+                    self.settle_dealer_turn().await;
+                }
+            }
+            BlackjackMessage::TableAction { seat_id, action } => {
+                log::info!("\n\nBlackjackMessage::TableAction");
+                let game = self.state.game.get();
+                if game.players.get(&seat_id).is_none_or(|player| player.chain_id != Some(origin_chain_id)) || !game.is_turn_of(seat_id) {
+                    log::info!("Not the turn of seat {} from {:?}, ignoring {:?}", seat_id, origin_chain_id, action);
+                    return;
+                }
+                self.table_action_manager(seat_id, action);
+                self.settle_dealer_turn().await;
+            }
+            BlackjackMessage::ClosePlayChain => {
+                log::info!("\n\nBlackjackMessage::ClosePlayChain");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackMessage::ClosePlayChain"
+                );
+                // Live bets are settled first, the table closes once the round ends
+                if self.state.game.get().is_hand_in_play() {
+                    log::info!("Hand in play, Play Chain {:?} closes after the round", self.runtime.chain_id());
+                    self.set_play_chain_mode(PlayChainMode::Draining);
+                    return;
+                }
+                self.close_play_chain().await;
+                self.emit_table_snapshot();
+                log::info!("Play Chain {:?} closed", self.runtime.chain_id());
+            }
+            BlackjackMessage::RequestTableSeat { seat_id, balance, request_id } => {
+                log::info!("\n\nBlackjackMessage::RequestTableSeat");
+                if self.request_table_seat_manager(seat_id, balance, request_id, origin_chain_id).await.is_some() {
+                    // Rounds of the previous holder do not count, the new chain bets once it reconciles its buy-in
+                    self.state.seat_settled_rounds.remove(&seat_id).unwrap_or_else(|_| {
+                        panic!("Failed to reset the settled round of seat {}", seat_id);
+                    });
+                    self.state.seat_reconciled_rounds.remove(&seat_id).unwrap_or_else(|_| {
+                        panic!("Failed to reset the reconciled round of seat {}", seat_id);
+                    });
+                    let player = self.state.game.get().players.get(&seat_id).cloned().expect("seated player missing");
+                    let sequence = self.next_table_sequence();
+                    self.emit_table_event(BlackjackEvent::PlayerSeated { sequence, player });
+                    self.report_play_chain_heartbeat();
+                }
+                log::info!("User {:?} RequestTableSeat to Play Chain {:?}", origin_chain_id, self.runtime.chain_id());
+            }
+            BlackjackMessage::LeaveTable { seat_id } => {
+                log::info!("\n\nBlackjackMessage::LeaveTable");
+                if let Some(forfeited) = self.leave_table_manager(seat_id, origin_chain_id) {
+                    self.message_manager(origin_chain_id, BlackjackMessage::SeatReleased { seat_id, forfeited });
+                    let sequence = self.next_table_sequence();
+                    self.emit_table_event(BlackjackEvent::PlayerLeft { sequence, seat_id });
+                    self.settle_dealer_turn().await;
+                    self.offer_free_seats().await;
+                    self.report_play_chain_heartbeat();
+                }
+                log::info!("User {:?} LeaveTable on Play Chain {:?}", origin_chain_id, self.runtime.chain_id());
+            }
+            BlackjackMessage::PlayChainAssigned { public_chain } => {
+                log::info!("\n\nBlackjackMessage::PlayChainAssigned");
+                assert_eq!(
+                    origin_chain_id,
+                    self.runtime.application_parameters().master_chain,
+                    "MasterChain Authorization Required for BlackjackMessage::PlayChainAssigned"
+                );
+                log::info!("Play Chain {:?} listed on public chain {:?}", self.runtime.chain_id(), public_chain);
+                self.state.play_chain_public_chain.set(Some(public_chain));
+                let mode = match self.state.private_table.get() {
+                    Some(_) => PlayChainMode::Private,
+                    None => PlayChainMode::Open,
+                };
+                self.state.play_chain_mode.set(mode);
+                self.offer_free_seats().await;
+                self.report_play_chain_listing();
+                self.report_play_chain_heartbeat();
+            }
+            BlackjackMessage::Spectate => {
+                log::info!("\n\nBlackjackMessage::Spectate");
+                let app_id = self.runtime.application_id().forget_abi();
+                self.runtime.subscribe_to_events(origin_chain_id, app_id, BLACKJACK_STREAM_NAME.into());
+                self.runtime.subscribe_to_events(origin_chain_id, app_id, CHAT_STREAM_NAME.into());
+                self.spectator_manager(origin_chain_id, MutationReason::AddNew).await;
+
+                // Spectators start from a full snapshot instead of waiting for the next one
+                let game = self.state.game.get().data_for_event();
+                self.message_manager(origin_chain_id, BlackjackMessage::TableSnapshot { game });
+                log::info!("User {:?} spectating Play Chain {:?}", origin_chain_id, self.runtime.chain_id());
+            }
+            BlackjackMessage::StopSpectating => {
+                log::info!("\n\nBlackjackMessage::StopSpectating");
+                let app_id = self.runtime.application_id().forget_abi();
+                self.runtime.unsubscribe_from_events(origin_chain_id, app_id, BLACKJACK_STREAM_NAME.into());
+                self.runtime.unsubscribe_from_events(origin_chain_id, app_id, CHAT_STREAM_NAME.into());
+                self.spectator_manager(origin_chain_id, MutationReason::Remove).await;
+                log::info!("User {:?} stopped spectating Play Chain {:?}", origin_chain_id, self.runtime.chain_id());
+            }
+            BlackjackMessage::ChatMessage { text } => {
+                log::info!("\n\nBlackjackMessage::ChatMessage");
+                match sanitize_chat_text(&text) {
+                    Some(text) => self.chat_manager(origin_chain_id, text, None).await,
+                    None => log::info!(
+                        "Dropping chat message from {:?}, empty or over {} characters",
+                        origin_chain_id,
+                        MAX_CHAT_MESSAGE_LENGTH
+                    ),
+                }
+            }
+            BlackjackMessage::ChatEmote { emote } => {
+                log::info!("\n\nBlackjackMessage::ChatEmote");
+                self.chat_manager(origin_chain_id, String::new(), Some(emote)).await;
+            }
+            BlackjackMessage::ResyncTable => {
+                log::info!("\n\nBlackjackMessage::ResyncTable");
+                let game = self.state.game.get().data_for_event();
+                log::info!("Sending table snapshot at sequence {} to {:?}", game.sequence, origin_chain_id);
+                self.message_manager(origin_chain_id, BlackjackMessage::TableSnapshot { game });
+            }
+            // * Master Chain
+            BlackjackMessage::RequestPublicChains => {
+                log::info!("\n\nBlackjackMessage::RequestPublicChains");
+                let chains = self.live_public_chains_registry().await;
+                log::info!("Sending {} live public chain(s) to {:?}", chains.len(), origin_chain_id);
+                self.message_manager(origin_chain_id, BlackjackMessage::PublicChains { chains });
+            }
+            BlackjackMessage::PlayChainListed { chain_id } => {
+                log::info!("\n\nBlackjackMessage::PlayChainListed");
+                assert!(
+                    self.public_chain_registry_status(origin_chain_id).await.is_some(),
+                    "PublicChain Authorization Required for BlackjackMessage::PlayChainListed"
+                );
+                log::info!("Play Chain {:?} listed on public chain {:?}", chain_id, origin_chain_id);
+                self.message_manager(chain_id, BlackjackMessage::PlayChainAssigned { public_chain: origin_chain_id });
+            }
+            // * Schema
+            BlackjackMessage::Versioned { .. }
+            | BlackjackMessage::LegacyFindPlayChainResult { .. }
+            | BlackjackMessage::LegacyRequestTableSeatResult { .. }
+            | BlackjackMessage::LegacyRequestTableSeat { .. }
+            | BlackjackMessage::LegacyFindPlayChain => {
+                panic!("BlackjackMessage::into_current must unwrap {:?}", message);
+            }
+        }
+    }
 
     // * Stream Subscriber
     async fn process_streams(&mut self, updates: Vec<StreamUpdate>) {
         for update in updates {
-            assert_eq!(update.stream_id.stream_name, BLACKJACK_STREAM_NAME.into());
+            let stream_name = update.stream_id.stream_name.clone();
+            assert!(
+                stream_name == BLACKJACK_STREAM_NAME.into() || stream_name == CHAT_STREAM_NAME.into(),
+                "Unexpected stream {:?}",
+                stream_name
+            );
             assert_eq!(update.stream_id.application_id, self.runtime.application_id().forget_abi().into());
             for index in update.new_indices() {
-                let event = self.runtime.read_event(update.chain_id, BLACKJACK_STREAM_NAME.into(), index);
+                let event: BlackjackEvent = self.runtime.read_event(update.chain_id, stream_name.clone(), index);
+                let Some(event) = event.into_current() else {
+                    log::info!("Skipping event {} from {:?} that does not decode with this schema", index, update.chain_id);
+                    continue;
+                };
                 match event {
                     BlackjackEvent::GameState { game } => {
                         log::info!("\nUser {:?} received new game state:\n {:?}", self.runtime.chain_id(), game);
-                        self.state.event_game_state.set(game);
+                        self.apply_event_game_state(game);
+                    }
+                    BlackjackEvent::TableChat { entry } => {
+                        self.receive_chat_entry(entry).await;
+                    }
+                    table_event => {
+                        log::info!("\nUser {:?} received table event:\n {:?}", self.runtime.chain_id(), table_event);
+                        self.apply_table_event(update.chain_id, table_event);
                     }
                 }
             }
@@ -449,6 +1359,7 @@ impl Contract for BlackjackContract {
 
 impl BlackjackContract {
     fn message_manager(&mut self, destination: ChainId, message: BlackjackMessage) {
+        let message = message.baseline_reply().unwrap_or_else(|| BlackjackMessage::versioned(&message));
         self.runtime.prepare_message(message).with_tracking().send_to(destination);
     }
 
@@ -462,6 +1373,35 @@ impl BlackjackContract {
         }
     }
 
+    fn bankroll_claim_bonus(&mut self) -> Amount {
+        let owner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        let response = self.runtime.call_application(true, bankroll_app_id, &BankrollOperation::ClaimBonus { owner });
+        match response {
+            BankrollResponse::Balance(balance) => balance,
+            response => panic!("Unexpected response from Bankroll application: {response:?}"),
+        }
+    }
+
+    fn bankroll_check_bet(&mut self, amount: Amount) {
+        let owner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        let response = self
+            .runtime
+            .call_application(true, bankroll_app_id, &BankrollOperation::CheckBet { owner, amount });
+        match response {
+            BankrollResponse::Ok => {}
+            BankrollResponse::BetRefused(reason) => panic!("bet refused: {}", reason),
+            response => panic!("Unexpected response from Bankroll application: {response:?}"),
+        }
+    }
+
+    fn bankroll_end_session(&mut self) {
+        let owner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        self.runtime.call_application(true, bankroll_app_id, &BankrollOperation::EndSession { owner });
+    }
+
     fn bankroll_update_balance(&mut self, amount: Amount) {
         let owner = self.runtime.application_id().into();
         let bankroll_app_id = self.runtime.application_parameters().bankroll;
@@ -469,12 +1409,64 @@ impl BlackjackContract {
             .call_application(true, bankroll_app_id, &BankrollOperation::UpdateBalance { owner, amount });
     }
 
+    fn bankroll_escrow_buy_in(&mut self, amount: Amount) -> Amount {
+        let owner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        let response = self
+            .runtime
+            .call_application(true, bankroll_app_id, &BankrollOperation::EscrowBuyIn { owner, amount });
+        match response {
+            BankrollResponse::Escrow(escrow) => escrow,
+            BankrollResponse::BetRefused(reason) => panic!("buy-in refused: {}", reason),
+            response => panic!("Unexpected response from Bankroll application: {response:?}"),
+        }
+    }
+
+    fn bankroll_release_escrow(&mut self) {
+        let owner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        self.runtime
+            .call_application(true, bankroll_app_id, &BankrollOperation::ReleaseEscrow { owner });
+    }
+
+    fn bankroll_settle_escrow(&mut self, amount: Amount) {
+        let owner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        self.runtime
+            .call_application(true, bankroll_app_id, &BankrollOperation::SettleEscrow { owner, amount });
+    }
+
+    fn bankroll_escrow(&mut self) -> Amount {
+        let owner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        let response = self.runtime.call_application(true, bankroll_app_id, &BankrollOperation::Escrow { owner });
+        match response {
+            BankrollResponse::Escrow(escrow) => escrow,
+            response => panic!("Unexpected response from Bankroll application: {response:?}"),
+        }
+    }
+
+    fn bankroll_debt(&mut self, debt_id: u64) -> Option<DebtRecord> {
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        let response = self.runtime.call_application(true, bankroll_app_id, &BankrollOperation::Debt { debt_id });
+        match response {
+            BankrollResponse::Debt(debt_record) => debt_record,
+            response => panic!("Unexpected response from Bankroll application: {response:?}"),
+        }
+    }
+
     fn bankroll_notify_debt(&mut self, amount: Amount, target_chain: ChainId) {
         let bankroll_app_id = self.runtime.application_parameters().bankroll;
         self.runtime
             .call_application(true, bankroll_app_id, &BankrollOperation::NotifyDebt { amount, target_chain });
     }
 
+    fn bankroll_retry_debt(&mut self, debt_id: u64, target_chain: ChainId) {
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        self.runtime
+            .call_application(true, bankroll_app_id, &BankrollOperation::RetryDebt { debt_id, target_chain });
+    }
+
     fn bankroll_transfer_token_pot(&mut self, amount: Amount, target_chain: ChainId) {
         let bankroll_app_id = self.runtime.application_parameters().bankroll;
         self.runtime
@@ -519,30 +1511,64 @@ impl BlackjackContract {
         log::info!("Updating profile balance and bet data");
         let balance = self.bankroll_get_balance();
         log::info!("Retrieved balance from bankroll: {}", balance);
+        self.state.profile.get_mut().update_balance(balance);
+        self.calculate_profile_bet_data();
+        log::info!("Profile updated - balance: {}, bet_data: {:?}", balance, self.state.profile.get().bet_data);
+    }
+    fn calculate_profile_bet_data(&mut self) {
+        // Table stakes clamp the chip ladder, otherwise it follows the balance
+        let stakes = self.state.table_stakes.get().clone();
         let profile = self.state.profile.get_mut();
-        profile.update_balance(balance);
-        profile.calculate_bet_data();
-        log::info!("Profile updated - balance: {}, bet_data: {:?}", balance, profile.bet_data);
+        match stakes {
+            Some(stakes) => profile.calculate_table_bet_data(&stakes),
+            None => profile.calculate_bet_data(),
+        }
+    }
+    fn known_public_chains(&mut self) -> Vec<ChainId> {
+        // Fall back to the deployment list until the Master Chain registry was synced
+        match self.state.public_chains.get().clone() {
+            Some(public_chains) => public_chains,
+            None => self.runtime.application_parameters().public_chains,
+        }
     }
     fn get_public_chain(&mut self) -> ChainId {
+        let public_chains = self.known_public_chains();
         let i = get_random_value(
             0,
-            self.runtime.application_parameters().public_chains.len() as u8,
+            public_chains.len() as u8,
             self.runtime.system_time().to_string(),
             self.runtime.system_time().to_string(),
         )
         .unwrap_or(0);
 
-        *self.runtime.application_parameters().public_chains.get(i as usize).unwrap_or_else(|| {
+        *public_chains.get(i as usize).unwrap_or_else(|| {
             panic!("unable to find public chain");
         })
     }
+    fn get_other_public_chain(&mut self, exclude: Option<ChainId>) -> ChainId {
+        let public_chains = self.known_public_chains();
+        let candidates: Vec<ChainId> = public_chains.iter().filter(|c| Some(**c) != exclude).cloned().collect();
+        if candidates.is_empty() {
+            return self.get_public_chain();
+        }
+
+        let i = get_random_value(
+            0,
+            candidates.len() as u8,
+            self.runtime.system_time().to_string(),
+            self.runtime.system_time().to_string(),
+        )
+        .unwrap_or(0);
+
+        *candidates.get(i as usize).unwrap_or(&candidates[0])
+    }
     fn process_find_play_chain_result(&mut self, origin_chain_id: ChainId, chain_id: Option<ChainId>) -> bool {
         if let Some(chain) = chain_id {
             log::info!("\nFindPlayChain Result Received at {:?} from: {:?}\n", self.runtime.chain_id(), origin_chain_id);
             log::info!("Available Chain ID {:?}", chain);
             self.state.user_status.set(UserStatus::PlayChainFound);
             self.state.find_play_chain_retry.set(0);
+            self.state.pending_request.set(None);
             self.state.user_play_chain.set(Some(chain));
             self.message_manager(chain, BlackjackMessage::Subscribe);
             return true;
@@ -553,16 +1579,111 @@ impl BlackjackContract {
             log::info!("FindPlayChain Result Received : No Chain ID found!");
             self.state.user_status.set(UserStatus::PlayChainUnavailable);
             self.state.find_play_chain_retry.set(0);
+            self.state.pending_request.set(None);
             self.state.user_play_chain.set(None);
+
+            // The known public chains may be stale, ask the Master Chain for the live ones
+            let master_chain = self.runtime.application_parameters().master_chain;
+            self.message_manager(master_chain, BlackjackMessage::RequestPublicChains);
             return false;
         }
 
         log::info!("Retrying FindPlayChain!");
         let next_chain_id = self.get_public_chain();
         self.state.find_play_chain_retry.set(retry_count.saturating_add(1));
-        self.message_manager(next_chain_id, BlackjackMessage::FindPlayChain);
+        let filter = self.state.find_play_chain_filter.get().clone();
+        let request_id = self.start_request();
+        self.send_request(next_chain_id, BlackjackMessage::FindPlayChain { filter, request_id });
         false
     }
+    fn release_found_play_chain(&mut self) {
+        // A request past its deadline no longer blocks a new one
+        let request_in_flight = self.is_request_in_flight();
+        match self.state.user_status.get() {
+            UserStatus::FindPlayChain if request_in_flight => {
+                panic!("still waiting response from previous FindPlayChain");
+            }
+            UserStatus::InMultiPlayerGame | UserStatus::InSinglePlayerGame => {
+                panic!("user already in game, FindPlayChain not allowed");
+            }
+            UserStatus::RequestingTableSeat if request_in_flight => {
+                panic!("user is requesting table seat, FindPlayChain not allowed");
+            }
+            UserStatus::Spectating => {
+                panic!("user is spectating, please StopSpectating first");
+            }
+            UserStatus::PlayChainFound | UserStatus::RequestTableSeatFail | UserStatus::RequestingTableSeat | UserStatus::Waitlisted => {
+                let play_chain_id = self.state.user_play_chain.get().unwrap();
+                if *self.state.on_waitlist.get() {
+                    self.message_manager(play_chain_id, BlackjackMessage::LeaveWaitlist);
+                    self.reset_waitlist_session();
+                }
+                // An expired seat request may still hold the buy-in
+                self.bankroll_release_escrow();
+                self.message_manager(play_chain_id, BlackjackMessage::Unsubscribe);
+                self.state.user_play_chain.set(None);
+                self.state.event_game_state.clear();
+            }
+            _ => {}
+        }
+    }
+    fn start_request(&mut self) -> u64 {
+        let request_id = self.state.request_sequence.get().saturating_add(1);
+        self.state.request_sequence.set(request_id);
+        let current_time = self.runtime.system_time().micros();
+        let pending_request = PendingRequest::new(request_id, current_time, REQUEST_DEADLINE_DURATION_IN_MICROS);
+        self.state.pending_request.set(Some(pending_request));
+        request_id
+    }
+    fn send_request(&mut self, chain_id: ChainId, message: BlackjackMessage) {
+        self.state.pending_request_message.set(Some((chain_id, message.clone())));
+        self.message_manager(chain_id, message);
+    }
+    fn resend_expired_request(&mut self) {
+        let current_time = self.runtime.system_time();
+        let Some(mut request) = self.state.pending_request.get().clone().filter(|request| request.is_expired(current_time)) else {
+            return;
+        };
+        let Some((chain_id, message)) = self.state.pending_request_message.get().clone() else {
+            return;
+        };
+
+        if !request.can_resend() {
+            log::info!("Request {} got no reply after {} attempts, giving up", request.request_id, request.attempts);
+            match self.state.user_status.get() {
+                UserStatus::FindPlayChain => self.state.user_status.set(UserStatus::PlayChainUnavailable),
+                UserStatus::RequestingTableSeat => {
+                    self.bankroll_release_escrow();
+                    if *self.state.on_waitlist.get() {
+                        self.state.user_status.set(UserStatus::Waitlisted);
+                    } else {
+                        self.state.user_status.set(UserStatus::RequestTableSeatFail);
+                    }
+                }
+                _ => {}
+            }
+            self.state.pending_request.set(None);
+            return;
+        }
+
+        request.resend(current_time.micros(), REQUEST_DEADLINE_DURATION_IN_MICROS);
+        log::info!("Request {} expired, sending attempt {} to {:?}", request.request_id, request.attempts, chain_id);
+        self.state.pending_request.set(Some(request));
+        self.message_manager(chain_id, message);
+    }
+    fn is_request_in_flight(&mut self) -> bool {
+        let current_time = self.runtime.system_time();
+        self.state
+            .pending_request
+            .get()
+            .as_ref()
+            .is_some_and(|request| !request.is_expired(current_time))
+    }
+    fn is_current_request(&self, request_id: u64, expected_status: UserStatus) -> bool {
+        // requests from the baseline bytecode carry no id, their replies come back with request id 0
+        let pending_request_id = self.state.pending_request.get().as_ref().map_or(0, |request| request.request_id);
+        pending_request_id == request_id && self.state.user_status.get().eq(&expected_status)
+    }
     fn add_user_to_new_single_player_game(&mut self) {
         let balance = self.state.profile.get().balance;
         let chain_id = self.runtime.chain_id();
@@ -575,40 +1696,323 @@ impl BlackjackContract {
         );
         let new_player = Player::new(seat_id, balance, chain_id);
 
-        self.state.player_seat_map.insert(&seat_id, new_player.clone()).unwrap_or_else(|_| {
-            panic!("Failed to update Player Seat Map for {:?} on add_user_to_new_single_player_game", chain_id);
-        });
-        self.state.user_status.set(UserStatus::InSinglePlayerGame);
-        self.state.profile.get_mut().update_seat(seat_id);
+        self.state.player_seat_map.insert(&seat_id, new_player.clone()).unwrap_or_else(|_| {
+            panic!("Failed to update Player Seat Map for {:?} on add_user_to_new_single_player_game", chain_id);
+        });
+        self.state.user_status.set(UserStatus::InSinglePlayerGame);
+        self.state.profile.get_mut().update_seat(seat_id);
+
+        let current_time = self.runtime.system_time().micros();
+        let mut single_player_game = self.create_single_player_blackjack_game();
+        single_player_game.update_status(BlackjackStatus::WaitingForBets);
+        single_player_game.register_update_player(seat_id, new_player);
+        single_player_game.sequence = single_player_game.sequence.saturating_add(1);
+        single_player_game.set_time_limit(current_time, ONE_MINUTE_DURATION_IN_MICROS);
+        self.state.single_player_game.set(single_player_game.clone());
+        log::info!("Single player game created successfully - status: {:?}", single_player_game.status);
+    }
+    fn reset_multi_player_session(&mut self) {
+        // A seat left mid-hand keeps its escrow until the Play Chain reports the forfeited bet
+        if self.state.pending_leave.get().is_none() {
+            self.bankroll_release_escrow();
+        }
+        self.state.table_stakes.set(None);
+        self.update_profile_balance_and_bet_data();
+        self.bankroll_end_session();
+        let profile = self.state.profile.get_mut();
+        profile.remove_seat();
+        profile.update_chip_stack(vec![]);
+        self.state.user_status.set(UserStatus::Idle);
+        self.state.user_play_chain.set(None);
+        self.state.event_game_state.clear();
+        self.state.table_spectator_count.set(0);
+        self.state.table_resync_pending.set(false);
+        self.state.chat_history.clear();
+        self.state.player_seat_map.clear();
+        self.state.last_settlement.set(None);
+        self.state.table_mode.set(None);
+    }
+    fn apply_event_game_state(&mut self, game: BlackjackGame) {
+        let current_sequence = self.state.event_game_state.get().sequence;
+        if game.sequence < current_sequence {
+            log::info!("Skipping game state {} older than {}", game.sequence, current_sequence);
+            return;
+        }
+        self.state.event_game_state.set(game);
+    }
+    fn apply_table_event(&mut self, play_chain_id: ChainId, event: BlackjackEvent) {
+        let Some(sequence) = event.sequence() else {
+            log::info!("Skipping table event outside of the table stream: {:?}", event);
+            return;
+        };
+        let current_sequence = self.state.event_game_state.get().sequence;
+        if sequence <= current_sequence {
+            log::info!("Skipping table event {} not newer than {}", sequence, current_sequence);
+            return;
+        }
+
+        // Every table event bumps the sequence by one, a jump means events were missed
+        if sequence != current_sequence.saturating_add(1) {
+            log::info!("Sequence gap after {} at event {}, resyncing table", current_sequence, sequence);
+            if self.state.user_play_chain.get().eq(&Some(play_chain_id)) && !*self.state.table_resync_pending.get() {
+                self.request_table_resync(play_chain_id);
+            }
+            return;
+        }
+
+        let game = self.state.event_game_state.get_mut();
+        match event {
+            BlackjackEvent::GameState { .. } | BlackjackEvent::Versioned { .. } | BlackjackEvent::TableChat { .. } => {}
+            BlackjackEvent::PlayerSeated { player, .. } => game.register_update_player(player.seat_id, player),
+            BlackjackEvent::PlayerLeft { seat_id, .. } => {
+                game.release_seat(seat_id);
+            }
+            BlackjackEvent::BetPlaced { seat_id, amount, .. } => game.place_bet(seat_id, amount),
+            BlackjackEvent::CardDealt { seat_id, card, .. } => {
+                // The first card of a round moves the bets into the pot, as the Play Chain did before dealing
+                if !game.is_hand_in_play() {
+                    game.start_round();
+                }
+                game.add_card(seat_id, card);
+            }
+            BlackjackEvent::PlayerActed { seat_id, action, .. } => {
+                log::info!("Seat {} acted: {:?}", seat_id, action);
+                if action == PlayerAction::Stand {
+                    game.stand(seat_id);
+                }
+            }
+            BlackjackEvent::DealerRevealed { hand, .. } => {
+                game.dealer.hand = hand;
+                game.update_status(BlackjackStatus::DealerTurn);
+            }
+            BlackjackEvent::RoundSettled { results, .. } => game.settle_round(&results),
+            BlackjackEvent::ShoeShuffled { count, .. } => game.count = count,
+            BlackjackEvent::SpectatorsChanged { count, .. } => self.state.table_spectator_count.set(count),
+        }
+        game.sequence = sequence;
+    }
+    fn request_table_resync(&mut self, play_chain_id: ChainId) {
+        self.state.table_resync_pending.set(true);
+        self.message_manager(play_chain_id, BlackjackMessage::ResyncTable);
+        log::info!("Sent ResyncTable message to play_chain: {:?}", play_chain_id);
+    }
+    fn reset_spectating_session(&mut self) {
+        self.state.user_status.set(UserStatus::Idle);
+        self.state.user_play_chain.set(None);
+        self.state.event_game_state.clear();
+        self.state.table_spectator_count.set(0);
+        self.state.table_resync_pending.set(false);
+        self.state.chat_history.clear();
+    }
+    fn reset_waitlist_session(&mut self) {
+        self.state.on_waitlist.set(false);
+        self.state.seat_offer.set(None);
+        self.state.table_mode.set(None);
+        if self.state.user_status.get().eq(&UserStatus::Waitlisted) {
+            self.state.user_status.set(UserStatus::PlayChainFound);
+        }
+    }
+    fn chat_play_chain(&mut self) -> ChainId {
+        match self.state.user_status.get() {
+            UserStatus::InMultiPlayerGame | UserStatus::Spectating => {}
+            current_status => {
+                panic!("please join or spectate a table to chat, user status is {:?}", current_status);
+            }
+        }
+        self.state.user_play_chain.get().expect("no Play Chain found")
+    }
+    async fn receive_chat_entry(&mut self, entry: ChatEntry) {
+        if self.state.chat_muted.contains_key(&entry.sender).await.unwrap_or_default() {
+            log::info!("Chat from {:?} is muted", entry.sender);
+            return;
+        }
+        push_chat_history(self.state.chat_history.get_mut(), entry);
+    }
+    fn add_user_to_new_multi_player_game(&mut self, seat_id: u8) {
+        let balance = self.state.profile.get().balance;
+        let chain_id = self.runtime.chain_id();
+        log::info!(
+            "Adding user to multi player game - chain_id: {:?}, balance: {}, seat_id: {}",
+            chain_id,
+            balance,
+            seat_id
+        );
+        self.state
+            .player_seat_map
+            .insert(&seat_id, Player::new(seat_id, balance, chain_id))
+            .unwrap_or_else(|_| {
+                panic!("Failed to update Player Seat Map for {:?} on add_user_to_new_multi_player_game", chain_id);
+            });
+        self.state.profile.get_mut().update_seat(seat_id);
+        self.state.user_status.set(UserStatus::InMultiPlayerGame);
+        log::info!("User successfully joined multi player game at seat: {}", seat_id);
+    }
+    async fn reconcile_seat_balance(&mut self) {
+        let escrow = self.bankroll_escrow();
+        let profile = self.state.profile.get();
+        let seat_id = profile.seat.expect("missing Player Seat ID");
+        if profile.balance != escrow {
+            log::info!("Seat {} balance {} reconciled to escrow {}", seat_id, profile.balance, escrow);
+            self.state.profile.get_mut().update_balance(escrow);
+            self.calculate_profile_bet_data();
+            if let Some(mut player) = self.state.player_seat_map.get(&seat_id).await.unwrap_or_default() {
+                player.balance = escrow;
+                self.state.player_seat_map.insert(&seat_id, player).unwrap_or_else(|_| {
+                    panic!("Failed to update Player Seat Map on reconcile_seat_balance");
+                });
+            }
+        }
+
+        // The Play Chain only takes bets once the seat reports the last round it settled
+        let round = self.state.last_settlement.get().as_ref().map_or(0, |last| last.round);
+        let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found");
+        self.message_manager(
+            play_chain_id,
+            BlackjackMessage::SeatReconciled {
+                seat_id,
+                balance: escrow,
+                round,
+            },
+        );
+    }
+    fn bound_seat_escrow(&mut self, stakes: Option<&TableStakes>) -> Amount {
+        let escrow = self.bankroll_escrow();
+        let Some(max_buy_in) = stakes.map(TableStakes::max_buy_in).filter(|max_buy_in| escrow > *max_buy_in) else {
+            return escrow;
+        };
+        // The table stakes set the buy-in, the rest of the balance goes back out of escrow
+        log::info!("Buy-in {} bounded to {} by the table stakes", escrow, max_buy_in);
+        self.bankroll_release_escrow();
+        self.bankroll_escrow_buy_in(max_buy_in)
+    }
+    async fn receive_round_settlement(&mut self, settlement: RoundSettlement) {
+        let last_round = self.state.last_settlement.get().as_ref().map_or(0, |last| last.round);
+        if settlement.round <= last_round {
+            log::info!("Round {} already settled, last settled round is {}", settlement.round, last_round);
+            return;
+        }
+
+        let seat_id = self.state.profile.get().seat;
+        match seat_id.and_then(|seat_id| settlement.result_for(seat_id)).cloned() {
+            Some(result) => {
+                let public_chain = self.settlement_public_chain(settlement.public_chain);
+                self.settle_seat_result(&result, public_chain).await;
+            }
+            None => log::info!("No bet from this chain in round {}", settlement.round),
+        }
+        self.state.last_settlement.set(Some(settlement));
+    }
+    async fn settle_seat_result(&mut self, result: &SeatSettlement, public_chain: ChainId) {
+        let escrow = self.bankroll_escrow();
+        let settled = escrow.saturating_sub(result.bet).saturating_add(result.payout);
+        log::info!(
+            "Settling seat {} with {:?}, bet: {}, payout: {}, escrow: {} -> {}",
+            result.seat_id,
+            result.outcome,
+            result.bet,
+            result.payout,
+            escrow,
+            settled
+        );
+        self.bankroll_settle_escrow(settled);
+
+        // Lost bets go to the public chain pool, which also covers the winnings through a debt
+        match result.outcome {
+            GameOutcome::PlayerWins => self.bankroll_notify_debt(result.payout.saturating_sub(result.bet), public_chain),
+            GameOutcome::DealerWins => self.bankroll_transfer_token_pot(result.bet, public_chain),
+            _ => {}
+        }
+
+        self.state.profile.get_mut().update_balance(settled);
+        self.calculate_profile_bet_data();
+        if let Some(mut player) = self.state.player_seat_map.get(&result.seat_id).await.unwrap_or_default() {
+            player.balance = settled;
+            player.reset_bet();
+            self.state.player_seat_map.insert(&result.seat_id, player).unwrap_or_else(|_| {
+                panic!("Failed to update Player Seat Map on settle_seat_result");
+            });
+        }
+    }
+    fn forfeit_seat_bet(&mut self, bet: Amount) {
+        if bet.is_zero() {
+            return;
+        }
+        let escrow = self.bankroll_escrow();
+        self.bankroll_settle_escrow(escrow.saturating_sub(bet));
+        let last_public_chain = self.state.last_settlement.get().as_ref().and_then(|last| last.public_chain);
+        let public_chain = self.settlement_public_chain(last_public_chain);
+        self.bankroll_transfer_token_pot(bet, public_chain);
+        log::info!("Forfeited bet {} sent to public chain {:?}", bet, public_chain);
+    }
+    fn settlement_public_chain(&mut self, public_chain: Option<ChainId>) -> ChainId {
+        // Fall back to the token pool address when the Play Chain was not listed
+        match public_chain.or(*self.state.token_pool_address.get()) {
+            Some(public_chain) => public_chain,
+            None => self.get_public_chain(),
+        }
+    }
+    async fn prepare_bet_round(&mut self) {
+        match self.state.user_status.get() {
+            UserStatus::InMultiPlayerGame => {
+                let game_status = &self.state.event_game_state.get().status;
+                match game_status {
+                    BlackjackStatus::WaitingForPlayer | BlackjackStatus::PlayerTurn | BlackjackStatus::DealerTurn => {
+                        panic!("game in play, not ready for placing bets, please wait for the next hands");
+                    }
+                    _ => {}
+                }
+                self.reconcile_seat_balance().await;
+            }
+            UserStatus::InSinglePlayerGame => {
+                let game_status = &self.state.single_player_game.get().status;
+                match game_status {
+                    BlackjackStatus::WaitingForPlayer | BlackjackStatus::PlayerTurn | BlackjackStatus::DealerTurn => {
+                        panic!("game in play, not ready for placing bets, please wait for the next hands");
+                    }
+                    BlackjackStatus::RoundEnded => {
+                        self.update_profile_balance_and_bet_data();
+                        self.prepare_next_single_player_bet_round().await;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {
+                panic!("Player not in any Single or MultiPlayerGame!");
+            }
+        }
+    }
+
+    async fn commit_bet(&mut self, amount: Amount, chip_stack: Vec<ChipPlacement>) {
+        match self.state.user_status.get() {
+            UserStatus::InMultiPlayerGame => {
+                log::info!("Bet MultiPlayerGame, amount: {}", amount);
+                self.multi_player_player_bet(amount).await;
+            }
+            _ => {
+                log::info!("Bet SinglePlayerGame, amount: {}", amount);
+                self.player_bet(amount).await;
+            }
+        }
+        self.state.profile.get_mut().update_chip_stack(chip_stack);
+    }
+
+    fn current_bet_data(&self) -> BetData {
+        self.state.profile.get().bet_data.clone().expect("missing Bet Data for placing bet")
+    }
 
-        let current_time = self.runtime.system_time().micros();
-        let mut single_player_game = self.create_single_player_blackjack_game();
-        single_player_game.update_status(BlackjackStatus::WaitingForBets);
-        single_player_game.register_update_player(seat_id, new_player);
-        single_player_game.sequence = single_player_game.sequence.saturating_add(1);
-        single_player_game.set_time_limit(current_time, ONE_MINUTE_DURATION_IN_MICROS);
-        self.state.single_player_game.set(single_player_game.clone());
-        log::info!("Single player game created successfully - status: {:?}", single_player_game.status);
+    /// Chips for a repeated bet, taken from the chips enabled now in case the stakes changed.
+    fn rebuild_chip_stack(&self, amount: Amount) -> Vec<ChipPlacement> {
+        self.current_bet_data()
+            .chip_stack_for(amount)
+            .unwrap_or_else(|error| panic!("previous bet can't be placed again: {}", error))
     }
-    fn add_user_to_new_multi_player_game(&mut self, seat_id: u8) {
-        let balance = self.state.profile.get().balance;
-        let chain_id = self.runtime.chain_id();
-        log::info!(
-            "Adding user to multi player game - chain_id: {:?}, balance: {}, seat_id: {}",
-            chain_id,
-            balance,
-            seat_id
-        );
-        self.state
-            .player_seat_map
-            .insert(&seat_id, Player::new(seat_id, balance, chain_id))
-            .unwrap_or_else(|_| {
-                panic!("Failed to update Player Seat Map for {:?} on add_user_to_new_multi_player_game", chain_id);
-            });
-        self.state.profile.get_mut().update_seat(seat_id);
-        self.state.user_status.set(UserStatus::InMultiPlayerGame);
-        log::info!("User successfully joined multi player game at seat: {}", seat_id);
+
+    fn chip_stack_amount(&self, chip_stack: &[ChipPlacement]) -> Amount {
+        self.current_bet_data()
+            .chip_stack_total(chip_stack)
+            .unwrap_or_else(|error| panic!("invalid chip stack: {}", error))
     }
+
     async fn player_bet(&mut self, amount: Amount) {
         log::info!("player_bet called with amount: {}", amount);
         if self.state.profile.get().bet_data.is_none() {
@@ -634,6 +2038,9 @@ impl BlackjackContract {
             panic!("maximum bet is {:?}", bet_data.max_bet);
         }
 
+        // Responsible-gaming limits
+        self.bankroll_check_bet(amount);
+
         let seat_id = user_profile.seat.unwrap();
         log::info!(
             "Bet validation passed - seat_id: {}, amount: {}, min_bet: {}, max_bet: {}, balance: {}",
@@ -659,12 +2066,101 @@ impl BlackjackContract {
         log::info!("Bet placed successfully for seat_id: {}, amount: {}", seat_id, amount);
     }
 
+    /// Take the bet back, no balance or limit checks apply to a bet of zero.
+    fn clear_single_player_bet(&mut self) {
+        let seat_id = self.state.profile.get().seat.expect("missing Player Seat ID");
+        let single_player_game = self.state.single_player_game.get_mut();
+        single_player_game.sequence = single_player_game.sequence.saturating_add(1);
+
+        let player = single_player_game.players.get_mut(&seat_id).expect("Player not found in single player game");
+        player.reset_bet();
+        self.state.player_seat_map.insert(&seat_id, player.clone()).unwrap_or_else(|_| {
+            panic!("Failed to update Player Seat Map on clear_single_player_bet");
+        });
+        log::info!("Bet cleared for seat_id: {}", seat_id);
+    }
+
+    fn pending_single_player_bet(&self) -> Amount {
+        let profile = self.state.profile.get();
+        let seat_id = profile.seat.expect("missing Seat ID");
+        let min_bet = profile.bet_data.as_ref().expect("missing Bet Data").min_bet;
+        let player = self
+            .state
+            .single_player_game
+            .get()
+            .players
+            .get(&seat_id)
+            .expect("Player not found in single player game");
+        if player.bet == Amount::ZERO {
+            min_bet
+        } else {
+            player.bet
+        }
+    }
+
     async fn multi_player_player_bet(&mut self, amount: Amount) {
-        // TODO: continue
+        log::info!("multi_player_player_bet called with amount: {}", amount);
+        let user_profile = self.state.profile.get().clone();
+        let bet_data = user_profile.bet_data.expect("missing Bet Data for placing bet");
+        let seat_id = user_profile.seat.expect("missing Player Seat ID");
+
+        if amount.gt(&Amount::ZERO) && amount.lt(&bet_data.min_bet) {
+            panic!("minimum bet is {:?}", bet_data.min_bet);
+        }
+        if amount.gt(&bet_data.max_bet) {
+            panic!("maximum bet is {:?}", bet_data.max_bet);
+        }
+        if amount.gt(&user_profile.balance) {
+            panic!("not enough Player balance");
+        }
+
+        // Responsible-gaming limits, clearing a bet is always allowed
+        if amount.gt(&Amount::ZERO) {
+            self.bankroll_check_bet(amount);
+        }
+
+        if let Some(mut player) = self.state.player_seat_map.get(&seat_id).await.unwrap_or_default() {
+            player.bet = amount;
+            self.state.player_seat_map.insert(&seat_id, player).unwrap_or_else(|_| {
+                panic!("Failed to update Player Seat Map on multi_player_player_bet");
+            });
+        }
+        let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found");
+        self.message_manager(play_chain_id, BlackjackMessage::PlaceTableBet { seat_id, amount });
+        log::info!("Sent PlaceTableBet {} for seat {} to play_chain: {:?}", amount, seat_id, play_chain_id);
+    }
+
+    fn multi_player_deal_bet(&mut self) {
+        let seat_id = self.state.profile.get().seat.expect("missing Player Seat ID");
+        let game = self.state.event_game_state.get();
+        if game.status.ne(&BlackjackStatus::WaitingForBets) {
+            panic!("game in play, not ready for dealing bets, please wait for the next hands");
+        }
+        if game.players.get(&seat_id).is_none_or(|player| player.bet.is_zero()) {
+            panic!("please place a bet before dealing");
+        }
+        let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found");
+        self.message_manager(play_chain_id, BlackjackMessage::DealTable { seat_id });
+        log::info!("Sent DealTable for seat {} to play_chain: {:?}", seat_id, play_chain_id);
+    }
+
+    fn multi_player_action(&mut self, action: PlayerAction) {
+        let seat_id = self.state.profile.get().seat.expect("missing Player Seat ID");
+        if !self.state.event_game_state.get().is_turn_of(seat_id) {
+            panic!("not the player turn");
+        }
+        let play_chain_id = self.state.user_play_chain.get().expect("no Play Chain found");
+        self.message_manager(play_chain_id, BlackjackMessage::TableAction { seat_id, action });
+        log::info!("Sent TableAction {:?} for seat {} to play_chain: {:?}", action, seat_id, play_chain_id);
     }
 
     async fn deal_draw_single_player(&mut self) -> GameOutcome {
         log::info!("deal_draw_single_player called");
+
+        // Responsible-gaming limits apply to the bet that is about to be dealt
+        let pending_bet = self.pending_single_player_bet();
+        self.bankroll_check_bet(pending_bet);
+
         let profile = self.state.profile.get_mut();
         let seat_id = profile.seat.expect("missing Seat ID");
 
@@ -683,7 +2179,12 @@ impl BlackjackContract {
 
         let (bet_amount, latest_balance) = player.deal_bet(bet_data.clone().unwrap().min_bet, profile.balance);
         log::info!("Player dealt - bet_amount: {}, latest_balance: {}", bet_amount, latest_balance);
+        let dealt_chip_stack = bet_data.as_ref().and_then(|data| data.chip_stack_for(bet_amount).ok()).unwrap_or_default();
         profile.update_balance(latest_balance);
+        if profile.chip_stack.is_empty() {
+            profile.update_chip_stack(dealt_chip_stack);
+        }
+        profile.commit_chip_stack();
         single_player_game.pot.saturating_add_assign(bet_amount);
         log::info!("Deal complete - game pot: {}, player balance: {}", single_player_game.pot, latest_balance);
 
@@ -733,42 +2234,591 @@ impl BlackjackContract {
     }
     // * Play Chain
     fn event_manager(&mut self, event: BlackjackEvent) {
-        self.runtime.emit(BLACKJACK_STREAM_NAME.into(), &event);
+        self.runtime.emit(BLACKJACK_STREAM_NAME.into(), &BlackjackEvent::versioned(&event));
+    }
+    fn next_table_sequence(&mut self) -> u64 {
+        let game = self.state.game.get_mut();
+        game.sequence = game.sequence.saturating_add(1);
+        game.sequence
+    }
+    fn emit_table_event(&mut self, event: BlackjackEvent) {
+        let sequence = event.sequence();
+        self.event_manager(event);
+        if sequence.is_some_and(|sequence| sequence % TABLE_SNAPSHOT_INTERVAL == 0) {
+            self.emit_table_snapshot();
+        }
     }
-    fn request_table_seat_manager(&mut self, seat_id: u8, balance: Amount, origin_chain_id: ChainId) -> Option<()> {
+    fn emit_table_snapshot(&mut self) {
+        let game = self.state.game.get().data_for_event();
+        self.event_manager(BlackjackEvent::GameState { game });
+    }
+    async fn request_table_seat_manager(&mut self, seat_id: u8, balance: Amount, request_id: u64, origin_chain_id: ChainId) -> Option<()> {
         log::info!(
-            "request_table_seat_manager - seat_id: {}, balance: {}, origin_chain: {:?}",
+            "request_table_seat_manager - seat_id: {}, balance: {}, request_id: {}, origin_chain: {:?}",
             seat_id,
             balance,
+            request_id,
             origin_chain_id
         );
+        let stakes = self.state.play_chain_stakes.get().clone();
+        let mode = *self.state.play_chain_mode.get();
+        let is_guest = self.state.private_table_guests.contains_key(&origin_chain_id).await.unwrap_or_default();
+        let current_time = self.runtime.system_time();
+        let held_for = self
+            .state
+            .seat_offers
+            .get(&seat_id)
+            .await
+            .unwrap_or_default()
+            .filter(|offer| !offer.is_expired(current_time) && offer.chain_id != origin_chain_id)
+            .map(|offer| offer.chain_id);
         let game = self.state.game.get_mut();
 
-        if game.is_seat_taken(seat_id) {
-            log::info!("Seat {} is already taken, rejecting request from {:?}", seat_id, origin_chain_id);
-            self.message_manager(origin_chain_id, BlackjackMessage::RequestTableSeatResult { seat_id, success: false });
+        // A resent request for a seat this chain already holds gets the same answer again
+        if game.players.get(&seat_id).is_some_and(|player| player.chain_id == Some(origin_chain_id)) {
+            log::info!("Seat {} already held by {:?}, repeating the result", seat_id, origin_chain_id);
+            let result = TableSeatResult {
+                seat_id,
+                success: true,
+                stakes,
+            };
+            self.message_manager(origin_chain_id, BlackjackMessage::RequestTableSeatResult { request_id, result });
             return None;
         }
 
-        log::info!("Seat {} is available, registering player from {:?}", seat_id, origin_chain_id);
-        let player = Player::new(seat_id, balance, origin_chain_id);
-        game.register_update_player(seat_id, player);
-        self.message_manager(origin_chain_id, BlackjackMessage::RequestTableSeatResult { seat_id, success: true });
+        let success = if mode == PlayChainMode::Private && !is_guest {
+            log::info!("Private table, {:?} has not entered the invite code", origin_chain_id);
+            false
+        } else if mode != PlayChainMode::Open && mode != PlayChainMode::Private {
+            log::info!("Play Chain is {:?}, rejecting request from {:?}", mode, origin_chain_id);
+            false
+        } else if game.is_seat_taken(seat_id) {
+            log::info!("Seat {} is already taken, rejecting request from {:?}", seat_id, origin_chain_id);
+            false
+        } else if let Some(waiting_chain) = held_for {
+            log::info!(
+                "Seat {} is offered to {:?}, rejecting request from {:?}",
+                seat_id,
+                waiting_chain,
+                origin_chain_id
+            );
+            false
+        } else if let Some(min_bet) = stakes.as_ref().map(|s| s.min_bet).filter(|min_bet| balance < *min_bet) {
+            log::info!(
+                "Balance {} below table min bet {}, rejecting request from {:?}",
+                balance,
+                min_bet,
+                origin_chain_id
+            );
+            false
+        } else {
+            log::info!("Seat {} is available, registering player from {:?}", seat_id, origin_chain_id);
+            let player = Player::new(seat_id, balance, origin_chain_id);
+            game.register_update_player(seat_id, player);
+            true
+        };
+
+        let result = TableSeatResult { seat_id, success, stakes };
+        self.message_manager(origin_chain_id, BlackjackMessage::RequestTableSeatResult { request_id, result });
+        if !success {
+            return None;
+        }
+        self.remove_from_waitlist(origin_chain_id).await;
         log::info!("Player from {:?} successfully registered at seat {}", origin_chain_id, seat_id);
         Some(())
     }
+    fn leave_table_manager(&mut self, seat_id: u8, origin_chain_id: ChainId) -> Option<Amount> {
+        log::info!("leave_table_manager - seat_id: {}, origin_chain: {:?}", seat_id, origin_chain_id);
+        let game = self.state.game.get_mut();
+
+        let seated_chain = game.players.get(&seat_id).and_then(|player| player.chain_id);
+        if seated_chain != Some(origin_chain_id) {
+            log::info!("Seat {} is not held by {:?}, ignoring LeaveTable", seat_id, origin_chain_id);
+            return None;
+        }
+
+        // A dealt bet stays in the pot, an undealt one was never collected
+        let forfeited = game.release_seat(seat_id);
+        log::info!("Seat {} released, forfeited bet: {}, pot: {}", seat_id, forfeited, game.pot);
+        Some(forfeited)
+    }
+    async fn close_play_chain(&mut self) {
+        let game = self.state.game.get();
+        let seats: Vec<(u8, Option<ChainId>)> = game.players.values().map(|player| (player.seat_id, player.chain_id)).collect();
+
+        let mut seated_chains = Vec::new();
+        for (seat_id, chain_id) in seats {
+            let forfeited = self.state.game.get_mut().release_seat(seat_id);
+            log::info!("Seat {} released on close, forfeited bet: {}", seat_id, forfeited);
+            let sequence = self.next_table_sequence();
+            self.emit_table_event(BlackjackEvent::PlayerLeft { sequence, seat_id });
+            seated_chains.extend(chain_id);
+        }
+
+        // Seated and waiting players move on to another table, spectators stop watching
+        let spectators = self.state.play_chain_spectators.indices().await.unwrap_or_default();
+        self.state.play_chain_spectators.clear();
+        let waitlist: Vec<ChainId> = self.state.seat_waitlist.get().iter().map(|entry| entry.chain_id).collect();
+        self.state.seat_waitlist.set(Vec::new());
+        self.state.seat_offers.clear();
+        for chain_id in seated_chains.into_iter().chain(spectators).chain(waitlist) {
+            self.message_manager(chain_id, BlackjackMessage::TableClosed);
+        }
+
+        if let Some(public_chain) = *self.state.play_chain_public_chain.get() {
+            self.message_manager(public_chain, BlackjackMessage::RemovePlayChain);
+        }
+        self.state.play_chain_public_chain.set(None);
+        self.state.play_chain_mode.set(PlayChainMode::Paused);
+    }
+    fn seat_reconciled_manager(&mut self, seat_id: u8, balance: Amount, round: u64, origin_chain_id: ChainId) -> Option<()> {
+        let game = self.state.game.get_mut();
+        let Some(player) = game.players.get_mut(&seat_id).filter(|player| player.chain_id == Some(origin_chain_id)) else {
+            log::info!("Seat {} is not held by {:?}, ignoring SeatReconciled", seat_id, origin_chain_id);
+            return None;
+        };
+        log::info!("Seat {} balance {} reconciled to {} after round {}", seat_id, player.balance, balance, round);
+        player.balance = balance;
+        self.state.seat_reconciled_rounds.insert(&seat_id, round).unwrap_or_else(|_| {
+            panic!("Failed to reconcile seat {}", seat_id);
+        });
+        Some(())
+    }
+    async fn is_seat_reconciled(&self, seat_id: u8) -> bool {
+        let settled_round = self.state.seat_settled_rounds.get(&seat_id).await.unwrap_or_default().unwrap_or_default();
+        let reconciled_round = self.state.seat_reconciled_rounds.get(&seat_id).await.unwrap_or_default();
+        reconciled_round.is_some_and(|reconciled_round| reconciled_round >= settled_round)
+    }
+    async fn place_table_bet_manager(&mut self, seat_id: u8, amount: Amount, origin_chain_id: ChainId) -> Option<()> {
+        let stakes = self.state.play_chain_stakes.get().clone();
+        let current_time = self.runtime.system_time().micros();
+        // Only balances reconciled after the last settled round are dealt
+        let is_reconciled = self.is_seat_reconciled(seat_id).await;
+        let game = self.state.game.get_mut();
+        let Some(player) = game.players.get(&seat_id).filter(|player| player.chain_id == Some(origin_chain_id)) else {
+            log::info!("Seat {} is not held by {:?}, ignoring PlaceTableBet", seat_id, origin_chain_id);
+            return None;
+        };
+        if game.is_hand_in_play() {
+            log::info!("Hand in play, rejecting bet {} from seat {}", amount, seat_id);
+            return None;
+        }
+        if amount > Amount::ZERO && !is_reconciled {
+            log::info!("Seat {} balance not reconciled since its last round, rejecting bet {}", seat_id, amount);
+            return None;
+        }
+        if amount > player.balance {
+            log::info!("Bet {} exceeds seat {} balance {}", amount, seat_id, player.balance);
+            return None;
+        }
+        if let Some(stakes) = stakes.filter(|stakes| amount > Amount::ZERO && (amount < stakes.min_bet || amount > stakes.max_bet)) {
+            log::info!("Bet {} outside table stakes {} - {}", amount, stakes.min_bet, stakes.max_bet);
+            return None;
+        }
+
+        // The first bet of a round starts the betting time
+        if !game.has_bets() {
+            game.set_time_limit(current_time, TABLE_BET_DURATION_IN_MICROS);
+        }
+        game.place_bet(seat_id, amount);
+        Some(())
+    }
+    fn can_deal_table(&self) -> bool {
+        let game = self.state.game.get();
+        let mode = *self.state.play_chain_mode.get();
+        if mode != PlayChainMode::Open && mode != PlayChainMode::Private {
+            log::info!("Play Chain is {:?}, not dealing", mode);
+            return false;
+        }
+        game.status == BlackjackStatus::WaitingForBets && game.has_bets()
+    }
+    fn check_deck_multi_player(&mut self) {
+        if self.state.game.get().count >= MINIMUM_BLACKJACK_DECK {
+            return;
+        }
+        let mut refill_deck = self.refill_deck();
+        let current_time = self.runtime.system_time().to_string();
+        let game = self.state.game.get_mut();
+        game.deck.add_cards(&mut refill_deck, current_time);
+        game.count = game.count.saturating_add(REFILL_BLACKJACK_DECK_COUNT);
+        let count = game.count;
+        let sequence = self.next_table_sequence();
+        self.emit_table_event(BlackjackEvent::ShoeShuffled { sequence, count });
+    }
+    /// Deal two cards to every seat with a bet and to the dealer, the second dealer card face down.
+    fn deal_multi_player_round(&mut self) {
+        self.check_deck_multi_player();
+        let current_time = self.runtime.system_time().micros();
+        let game = self.state.game.get_mut();
+        game.start_round();
+        game.set_time_limit(current_time, TABLE_TURN_DURATION_IN_MICROS);
+        let mut deal_order: Vec<Option<u8>> = game.betting_seats().into_iter().map(Some).collect();
+        deal_order.push(None);
+        log::info!("Dealing round to seats {:?}", deal_order);
+
+        for face_up in [true, false] {
+            for seat_id in deal_order.iter().copied() {
+                let game = self.state.game.get_mut();
+                let card = game.deck.deal_card().expect("Deck ran out of cards");
+                game.add_card(seat_id, card);
+                let card = if seat_id.is_none() && !face_up { 0 } else { card };
+                let sequence = self.next_table_sequence();
+                self.emit_table_event(BlackjackEvent::CardDealt { sequence, seat_id, card });
+            }
+        }
+    }
+    fn table_action_manager(&mut self, seat_id: u8, action: PlayerAction) {
+        let current_time = self.runtime.system_time().micros();
+        log::info!("Seat {} acts: {:?}", seat_id, action);
+        match action {
+            PlayerAction::Hit => {
+                let sequence = self.next_table_sequence();
+                self.emit_table_event(BlackjackEvent::PlayerActed { sequence, seat_id, action });
+                let game = self.state.game.get_mut();
+                let card = game.deck.deal_card().expect("Deck ran out of cards");
+                game.add_card(Some(seat_id), card);
+                game.set_time_limit(current_time, TABLE_TURN_DURATION_IN_MICROS);
+                let sequence = self.next_table_sequence();
+                self.emit_table_event(BlackjackEvent::CardDealt {
+                    sequence,
+                    seat_id: Some(seat_id),
+                    card,
+                });
+            }
+            PlayerAction::Stand => {
+                let game = self.state.game.get_mut();
+                game.stand(seat_id);
+                game.set_time_limit(current_time, TABLE_TURN_DURATION_IN_MICROS);
+                let sequence = self.next_table_sequence();
+                self.emit_table_event(BlackjackEvent::PlayerActed { sequence, seat_id, action });
+            }
+        }
+    }
+    /// Deal once the betting time runs out, and stand for a seat that let its turn time run out.
+    fn expire_table_time_limit(&mut self) {
+        let game = self.state.game.get();
+        if game.time_limit.is_none_or(|time_limit| time_limit > self.runtime.system_time()) {
+            return;
+        }
+        match game.status {
+            BlackjackStatus::WaitingForBets => {
+                if self.can_deal_table() {
+                    self.deal_multi_player_round();
+                }
+            }
+            BlackjackStatus::PlayerTurn => {
+                let seat_id = game.active_seat;
+                log::info!("Seat {} ran out of time, standing", seat_id);
+                self.table_action_manager(seat_id, PlayerAction::Stand);
+            }
+            _ => {}
+        }
+    }
+    /// Settles the round as soon as no seat has a hand left to play, a drained table then closes.
+    async fn settle_dealer_turn(&mut self) {
+        if self.state.game.get().status != BlackjackStatus::DealerTurn {
+            return;
+        }
+        self.settle_multi_player_round();
+        self.close_drained_play_chain().await;
+    }
+    fn settle_multi_player_round(&mut self) {
+        let rules = *self.state.play_chain_rules.get();
+        let game = self.state.game.get_mut();
+        while rules.dealer_draws(&game.dealer) {
+            let card = game.deck.deal_card().expect("Deck ran out of cards");
+            game.add_card(None, card);
+            log::info!("Dealer drew {}", format_card(card));
+        }
+        let hand = game.dealer.hand.clone();
+        let sequence = self.next_table_sequence();
+        self.emit_table_event(BlackjackEvent::DealerRevealed { sequence, hand });
+
+        // One settlement for the whole table, so every seat and spectator sees the same result
+        let game = self.state.game.get_mut();
+        let results = game.round_settlements();
+        let seated_chains: Vec<(u8, Option<ChainId>)> = game.players.values().map(|player| (player.seat_id, player.chain_id)).collect();
+        game.settle_round(&results);
+        let round = self.next_table_sequence();
+        self.emit_table_event(BlackjackEvent::RoundSettled {
+            sequence: round,
+            results: results.clone(),
+        });
+
+        let settlement = RoundSettlement {
+            play_chain_id: self.runtime.chain_id(),
+            round,
+            public_chain: *self.state.play_chain_public_chain.get(),
+            results,
+        };
+        for result in &settlement.results {
+            self.state.seat_settled_rounds.insert(&result.seat_id, round).unwrap_or_else(|_| {
+                panic!("Failed to record the settled round of seat {}", result.seat_id);
+            });
+        }
+        for (seat_id, chain_id) in seated_chains {
+            if let Some(chain_id) = chain_id.filter(|_| settlement.result_for(seat_id).is_some()) {
+                self.message_manager(
+                    chain_id,
+                    BlackjackMessage::RoundResult {
+                        settlement: settlement.clone(),
+                    },
+                );
+            }
+        }
+        log::info!("Round {} settled on {:?}: {:?}", round, settlement.play_chain_id, settlement.results);
+    }
+    async fn join_waitlist_manager(&mut self, chain_id: ChainId, seat_id: Option<u8>) -> Option<u32> {
+        let mode = *self.state.play_chain_mode.get();
+        let is_guest = self.state.private_table_guests.contains_key(&chain_id).await.unwrap_or_default();
+        if mode == PlayChainMode::Private && !is_guest {
+            log::info!("Private table, {:?} has not entered the invite code", chain_id);
+            return None;
+        }
+        if mode != PlayChainMode::Open && mode != PlayChainMode::Private {
+            log::info!("Play Chain is {:?}, not taking {:?} on the waiting list", mode, chain_id);
+            return None;
+        }
+        if self.state.game.get().players.values().any(|player| player.chain_id == Some(chain_id)) {
+            log::info!("{:?} is already seated, not taking it on the waiting list", chain_id);
+            return None;
+        }
+
+        let current_time = self.runtime.system_time();
+        let waitlist = self.state.seat_waitlist.get_mut();
+        if let Some(index) = waitlist.iter().position(|entry| entry.chain_id == chain_id) {
+            // Joining again only changes the wanted seat, the place in line is kept
+            waitlist[index].seat_id = seat_id;
+            return Some(index as u32 + 1);
+        }
+        if waitlist.len() >= MAX_WAITLIST_LENGTH {
+            log::info!("Waiting list is full, rejecting {:?}", chain_id);
+            return None;
+        }
+        waitlist.push(WaitlistEntry {
+            chain_id,
+            seat_id,
+            joined_at: current_time,
+        });
+        Some(waitlist.len() as u32)
+    }
+    async fn remove_from_waitlist(&mut self, chain_id: ChainId) {
+        self.state.seat_waitlist.get_mut().retain(|entry| entry.chain_id != chain_id);
+        for seat_id in self.state.seat_offers.indices().await.unwrap_or_default() {
+            let offer = self.state.seat_offers.get(&seat_id).await.unwrap_or_default();
+            if offer.is_some_and(|offer| offer.chain_id == chain_id) {
+                self.state.seat_offers.remove(&seat_id).unwrap_or_else(|_| {
+                    panic!("Failed to remove seat offer {}", seat_id);
+                });
+            }
+        }
+    }
+    async fn offer_free_seats(&mut self) {
+        // Every offer belongs to a waiting chain, so chains without a waiting list have nothing to do
+        if self.state.seat_waitlist.get().is_empty() {
+            return;
+        }
+        let current_time = self.runtime.system_time();
+        let mut offered_chains = Vec::new();
+        for seat_id in self.state.seat_offers.indices().await.unwrap_or_default() {
+            let Some(offer) = self.state.seat_offers.get(&seat_id).await.unwrap_or_default() else {
+                continue;
+            };
+            if !offer.is_expired(current_time) {
+                offered_chains.push(offer.chain_id);
+                continue;
+            }
+
+            // An unclaimed offer costs the place in line
+            log::info!("Seat {} offer to {:?} expired", seat_id, offer.chain_id);
+            self.state.seat_offers.remove(&seat_id).unwrap_or_else(|_| {
+                panic!("Failed to remove seat offer {}", seat_id);
+            });
+            self.state.seat_waitlist.get_mut().retain(|entry| entry.chain_id != offer.chain_id);
+            self.message_manager(offer.chain_id, BlackjackMessage::WaitlistJoined { position: None });
+        }
+
+        let mode = *self.state.play_chain_mode.get();
+        if mode != PlayChainMode::Open && mode != PlayChainMode::Private {
+            return;
+        }
+        for seat_id in 1..=MAX_BLACKJACK_PLAYERS as u8 {
+            let is_offered = self.state.seat_offers.contains_key(&seat_id).await.unwrap_or_default();
+            if is_offered || self.state.game.get().is_seat_taken(seat_id) {
+                continue;
+            }
+            let waitlist = self.state.seat_waitlist.get();
+            let Some(index) = next_waitlist_match(waitlist, seat_id, &offered_chains) else {
+                continue;
+            };
+
+            let offer = SeatOffer::new(waitlist[index].chain_id, seat_id, current_time.micros());
+            log::info!("Offering seat {} to {:?} until {:?}", seat_id, offer.chain_id, offer.deadline);
+            offered_chains.push(offer.chain_id);
+            self.state.seat_offers.insert(&seat_id, offer.clone()).unwrap_or_else(|_| {
+                panic!("Failed to offer seat {}", seat_id);
+            });
+            self.message_manager(offer.chain_id, BlackjackMessage::SeatAvailable { offer });
+        }
+    }
+    async fn spectator_manager(&mut self, chain_id: ChainId, status: MutationReason) {
+        let is_spectating = self.state.play_chain_spectators.contains_key(&chain_id).await.unwrap_or_default();
+        match status {
+            MutationReason::AddNew if !is_spectating => {
+                let current_time = self.runtime.system_time();
+                self.state.play_chain_spectators.insert(&chain_id, current_time).unwrap_or_else(|_| {
+                    panic!("Failed to add spectator {:?}", chain_id);
+                });
+            }
+            MutationReason::Remove if is_spectating => {
+                self.state.play_chain_spectators.remove(&chain_id).unwrap_or_else(|_| {
+                    panic!("Failed to remove spectator {:?}", chain_id);
+                });
+            }
+            _ => return,
+        }
+
+        let count = self.state.play_chain_spectators.count().await.unwrap_or_default() as u32;
+        let sequence = self.next_table_sequence();
+        self.emit_table_event(BlackjackEvent::SpectatorsChanged { sequence, count });
+    }
+    async fn chat_manager(&mut self, sender: ChainId, text: String, emote: Option<Emote>) {
+        let seat_id = self
+            .state
+            .game
+            .get()
+            .players
+            .values()
+            .find(|player| player.chain_id == Some(sender))
+            .map(|player| player.seat_id);
+        let is_spectator = self.state.play_chain_spectators.contains_key(&sender).await.unwrap_or_default();
+        if seat_id.is_none() && !is_spectator {
+            log::info!("Dropping chat from {:?}, neither seated nor spectating", sender);
+            return;
+        }
+
+        let current_time = self.runtime.system_time();
+        let last_sent = self.state.chat_last_sent.get(&sender).await.unwrap_or_default();
+        if is_chat_rate_limited(last_sent, current_time) {
+            log::info!("Dropping chat from {:?}, rate limited", sender);
+            return;
+        }
+        self.state.chat_last_sent.insert(&sender, current_time).unwrap_or_else(|_| {
+            panic!("Failed to update chat rate limit for {:?}", sender);
+        });
+
+        let entry = ChatEntry {
+            sender,
+            seat_id,
+            text,
+            emote,
+            sent_at: current_time,
+        };
+        push_chat_history(self.state.chat_history.get_mut(), entry.clone());
+        let event = BlackjackEvent::TableChat { entry };
+        self.runtime.emit(CHAT_STREAM_NAME.into(), &BlackjackEvent::versioned(&event));
+    }
+    fn set_play_chain_mode(&mut self, mode: PlayChainMode) {
+        self.state.play_chain_mode.set(mode);
+        if let Some(public_chain) = *self.state.play_chain_public_chain.get() {
+            self.message_manager(public_chain, BlackjackMessage::PlayChainModeUpdate { mode });
+        }
+
+        // Seated and waiting players learn why no hand or seat comes
+        let seated_chains: Vec<ChainId> = self.state.game.get().players.values().filter_map(|player| player.chain_id).collect();
+        let waitlist: Vec<ChainId> = self.state.seat_waitlist.get().iter().map(|entry| entry.chain_id).collect();
+        for chain_id in seated_chains.into_iter().chain(waitlist) {
+            self.message_manager(chain_id, BlackjackMessage::TableModeChanged { mode });
+        }
+    }
+    fn release_private_table(&mut self, owner: ChainId) {
+        self.state.private_table.set(None);
+        self.state.private_table_guests.clear();
+        // A closed table stays paused until the Master Chain lists it again
+        if *self.state.play_chain_mode.get() == PlayChainMode::Private {
+            self.set_play_chain_mode(PlayChainMode::Open);
+        }
+        self.message_manager(owner, BlackjackMessage::PrivateTableReservation { reserved: false });
+    }
+    fn release_idle_private_table(&mut self) {
+        let Some(table) = self.state.private_table.get().clone() else {
+            return;
+        };
+        let current_time = self.runtime.system_time();
+        if !self.state.game.get().players.is_empty() {
+            self.state.private_table_active_at.set(current_time);
+            return;
+        }
+        if PrivateTable::is_idle(*self.state.private_table_active_at.get(), current_time) {
+            log::info!(
+                "Private table {:?} idle without players, releasing it from {:?}",
+                self.runtime.chain_id(),
+                table.owner
+            );
+            self.release_private_table(table.owner);
+        }
+    }
+    async fn close_drained_play_chain(&mut self) {
+        if *self.state.play_chain_mode.get() != PlayChainMode::Draining || self.state.game.get().is_hand_in_play() {
+            return;
+        }
+        self.close_play_chain().await;
+        self.emit_table_snapshot();
+        log::info!("Drained Play Chain {:?} closed", self.runtime.chain_id());
+    }
+    fn report_play_chain_heartbeat(&mut self) {
+        let game = self.state.game.get();
+        let heartbeat = PlayChainHeartbeat {
+            sequence: game.sequence,
+            status: game.status.clone(),
+            player_number: game.players.len() as u8,
+        };
+        match *self.state.play_chain_public_chain.get() {
+            Some(public_chain) => {
+                log::info!("Reporting heartbeat {:?} to public chain {:?}", heartbeat, public_chain);
+                self.message_manager(public_chain, BlackjackMessage::PlayChainHeartbeat { heartbeat });
+            }
+            None => log::info!("Play Chain not listed on any public chain, skipping heartbeat"),
+        }
+    }
+    fn report_play_chain_listing(&mut self) {
+        let stakes = self.state.play_chain_stakes.get().clone();
+        let rules = *self.state.play_chain_rules.get();
+        if let Some(public_chain) = *self.state.play_chain_public_chain.get() {
+            log::info!("Reporting stakes {:?} and rules {:?} to public chain {:?}", stakes, rules, public_chain);
+            self.message_manager(public_chain, BlackjackMessage::PlayChainListing { stakes, rules });
+        }
+    }
     // * Public Chain
-    async fn search_available_play_chain(&mut self) -> Option<ChainId> {
-        for player_number in (0..MAX_BLACKJACK_PLAYERS).rev() {
-            // Safely check if the key in play_chain_set exists and the vector is non-empty
-            if let Some(vec) = self.state.play_chain_set.get(&(player_number as u8)).await.unwrap_or_default() {
-                log::info!("search_available_play_chain play_chain_set vec len is {:?}", vec.len());
-                if !vec.is_empty() {
-                    return vec.first().cloned();
+    async fn search_available_play_chain(&mut self, filter: &TableFilter) -> Option<ChainId> {
+        // Full tables sit in the MAX_BLACKJACK_PLAYERS bucket and are skipped
+        let current_time = self.runtime.system_time();
+        let mut candidates = Vec::new();
+        for player_number in 0..MAX_BLACKJACK_PLAYERS as u8 {
+            let chains = self.state.play_chain_set.get(&player_number).await.unwrap_or_default().unwrap_or_default();
+            log::info!("search_available_play_chain bucket {} has {} play chain(s)", player_number, chains.len());
+            for chain_id in chains {
+                let health = self.state.play_chain_health.get(&chain_id).await.unwrap_or_default();
+                if health.is_none_or(|health| health.is_stale(current_time)) {
+                    log::info!("search_available_play_chain skipping stale Play Chain {:?}", chain_id);
+                    continue;
                 }
+                let listing = self.state.play_chain_listing.get(&chain_id).await.unwrap_or_default().unwrap_or_default();
+                candidates.push((chain_id, player_number, listing));
             }
         }
-        None
+
+        let chain_id = select_play_chain(filter, &candidates)?;
+        let sequence = self.state.play_chain_assignments.get().saturating_add(1);
+        self.state.play_chain_assignments.set(sequence);
+
+        let mut listing = self.state.play_chain_listing.get(&chain_id).await.unwrap_or_default().unwrap_or_default();
+        listing.last_assigned = sequence;
+        self.state.play_chain_listing.insert(&chain_id, listing).unwrap_or_else(|_| {
+            panic!("Failed to update Play Chain Listing for {:?}", chain_id);
+        });
+        log::info!("search_available_play_chain assigned {:?} with sequence {}", chain_id, sequence);
+        Some(chain_id)
     }
     async fn play_chain_manager(&mut self, chain_id: ChainId, player_number: u8, status: MutationReason) {
         // REMOVAL PHASE: Remove from old bucket (for Update) or entirely (for Remove)
@@ -807,11 +2857,30 @@ impl BlackjackContract {
             self.state.play_chain_status.insert(&chain_id, player_number).unwrap_or_else(|_| {
                 panic!("Failed to update Play Chain Status for {:?}", chain_id);
             });
+
+            if status == MutationReason::AddNew {
+                self.state.play_chain_listing.insert(&chain_id, TableListing::default()).unwrap_or_else(|_| {
+                    panic!("Failed to update Play Chain Listing for {:?}", chain_id);
+                });
+
+                // A new Play Chain counts as seen until its first heartbeat is due
+                let current_time = self.runtime.system_time();
+                let health = PlayChainHealth::new(chain_id, PlayChainHeartbeat::default(), current_time);
+                self.state.play_chain_health.insert(&chain_id, health).unwrap_or_else(|_| {
+                    panic!("Failed to update Play Chain Health for {:?}", chain_id);
+                });
+            }
         } else {
             // Remove case: delete chain from status tracking entirely
             self.state.play_chain_status.remove(&chain_id).unwrap_or_else(|_| {
                 panic!("Failed to remove Play Chain Status for {:?}", chain_id);
             });
+            self.state.play_chain_listing.remove(&chain_id).unwrap_or_else(|_| {
+                panic!("Failed to remove Play Chain Listing for {:?}", chain_id);
+            });
+            self.state.play_chain_health.remove(&chain_id).unwrap_or_else(|_| {
+                panic!("Failed to remove Play Chain Health for {:?}", chain_id);
+            });
         }
     }
 
@@ -1050,8 +3119,8 @@ impl BlackjackContract {
         let mut dealer_hand_value = calculate_hand_value(&single_player_game.dealer.hand);
         log::info!("Initial dealer hand value: {}", dealer_hand_value);
 
-        // Keep dealing cards to dealer if hand value is lower than 17
-        while dealer_hand_value < 17 {
+        // Single player tables deal Standard rules, the dealer stands on all 17s
+        while RuleVariant::Standard.dealer_draws(&single_player_game.dealer) {
             let card = single_player_game.deck.deal_card().expect("Deck ran out of cards");
             single_player_game.dealer.hand.push(card);
             single_player_game.count = single_player_game.count.saturating_sub(1);
@@ -1122,4 +3191,89 @@ impl BlackjackContract {
 
         log::info!("Preparation for next round complete");
     }
+
+    // * Master Chain
+    async fn seed_public_chain_registry(&mut self) {
+        if *self.state.public_chain_registry_seeded.get() {
+            return;
+        }
+        for chain_id in self.runtime.application_parameters().public_chains {
+            self.state.public_chain_registry.insert(&chain_id, PublicChainStatus::Live).unwrap_or_else(|_| {
+                panic!("Failed to update Public Chain Registry for {:?}", chain_id);
+            });
+        }
+        self.state.public_chain_registry_seeded.set(true);
+    }
+    async fn live_public_chains_registry(&mut self) -> Vec<ChainId> {
+        self.seed_public_chain_registry().await;
+        let registry_keys = self
+            .state
+            .public_chain_registry
+            .indices()
+            .await
+            .expect("Failed to read public chain registry keys");
+        let mut live_chains = Vec::new();
+        for chain_id in registry_keys.into_iter() {
+            let status = self
+                .state
+                .public_chain_registry
+                .get(&chain_id)
+                .await
+                .expect("Failed to get public chain status");
+            if status == Some(PublicChainStatus::Live) {
+                live_chains.push(chain_id);
+            }
+        }
+        live_chains
+    }
+    async fn public_chain_registry_status(&mut self, chain_id: ChainId) -> Option<PublicChainStatus> {
+        self.seed_public_chain_registry().await;
+        self.state
+            .public_chain_registry
+            .get(&chain_id)
+            .await
+            .expect("Failed to get public chain status")
+    }
+    async fn public_chain_registry_manager(&mut self, chain_id: ChainId, status: Option<PublicChainStatus>) {
+        self.seed_public_chain_registry().await;
+        let registered = self.state.public_chain_registry.contains_key(&chain_id).await.unwrap_or(false);
+        if status != Some(PublicChainStatus::Live) {
+            let live_chains = self.live_public_chains_registry().await;
+            if let Err(error) = check_public_chain_retirement(&live_chains, chain_id) {
+                panic!("invalid public chain registry update: {}", error);
+            }
+        }
+
+        match status {
+            Some(PublicChainStatus::Live) => {
+                self.state.public_chain_registry.insert(&chain_id, PublicChainStatus::Live).unwrap_or_else(|_| {
+                    panic!("Failed to update Public Chain Registry for {:?}", chain_id);
+                });
+            }
+            Some(PublicChainStatus::Draining) => {
+                if !registered {
+                    panic!("public chain {:?} is not registered", chain_id);
+                }
+                self.state
+                    .public_chain_registry
+                    .insert(&chain_id, PublicChainStatus::Draining)
+                    .unwrap_or_else(|_| {
+                        panic!("Failed to update Public Chain Registry for {:?}", chain_id);
+                    });
+            }
+            None => {
+                if !registered {
+                    panic!("public chain {:?} is not registered", chain_id);
+                }
+                self.state.public_chain_registry.remove(&chain_id).unwrap_or_else(|_| {
+                    panic!("Failed to remove Public Chain Registry for {:?}", chain_id);
+                });
+            }
+        }
+
+        // Only live public chains keep answering FindPlayChain
+        let draining = status != Some(PublicChainStatus::Live);
+        self.message_manager(chain_id, BlackjackMessage::SetPublicChainDraining { draining });
+        log::info!("Public chain {:?} registry status: {:?}", chain_id, status);
+    }
 }
@@ -1,13 +1,19 @@
-use abi::blackjack::BlackjackGame;
+use abi::bet_chip_profile::{ChipPlacement, TableStakes};
+use abi::blackjack::{
+    BlackjackGame, PlayChainHeartbeat, PlayChainMode, PlayerAction, PrivateTable, PrivateTableInvite, RoundSettlement, RuleVariant, SeatOffer, SeatSettlement,
+    TableFilter, TableSeatResult,
+};
+use abi::chat::{ChatEntry, Emote};
+use abi::player_dealer::Player;
+use abi::schema::Envelope;
 use async_graphql::{Request, Response};
-use bankroll::BankrollAbi;
+use bankroll::{BankrollAbi, GamingLimits};
 use linera_sdk::linera_base_types::{Amount, ApplicationId, ChainId};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
     linera_base_types::{ContractAbi, ServiceAbi},
 };
 use serde::{Deserialize, Serialize};
-
 #[derive(Debug, Deserialize, Serialize)]
 pub struct BlackjackAbi;
 
@@ -26,32 +32,161 @@ pub enum BlackjackOperation {
     // * User Chain
     SubscribeTo { chain_id: ChainId },
     UnsubscribeFrom { chain_id: ChainId },
-    FindPlayChain {},
+    RefreshPublicChains {},
+    FindPlayChain { filter: Option<TableFilter> },
     RequestTableSeat { seat_id: u8 },
+    CancelRequest {},
     GetBalance {},
+    ClaimBonus {},
+    SetGamingLimits { limits: GamingLimits },
+    SelfExclude { duration_micros: u64 },
+    RetryDebt { debt_id: u64 },
     Bet { amount: Amount },
+    BetChips { chips: Vec<ChipPlacement> },
+    RepeatBet {},
+    DoubleBet {},
+    ClearBet {},
     DealBet {},
     Hit {},
     Stand {},
     StartSinglePlayerGame {},
     ExitSinglePlayerGame {},
+    LeaveTable {},
+    ResyncTable {},
+    Spectate { chain_id: ChainId },
+    StopSpectating {},
+    SendChatMessage { text: String },
+    SendEmote { emote: Emote },
+    MuteChat { chain_id: ChainId },
+    UnmuteChat { chain_id: ChainId },
+    ReservePrivateTable { play_chain_id: ChainId, invite: PrivateTableInvite }, // also from the Master Chain
+    ReleasePrivateTable { play_chain_id: ChainId },
+    EnterPrivateTable { chain_id: ChainId, invite_code: String },
+    JoinWaitlist { seat_id: Option<u8> },
+    LeaveWaitlist {},
+    // * Play Chain
+    Heartbeat {},
     // * Master Chain
+    RegisterPublicChain { chain_id: ChainId },
+    DeregisterPublicChain { chain_id: ChainId },
+    DrainPublicChain { chain_id: ChainId },
     AddPlayChain { target_public_chain: ChainId, play_chain_id: ChainId },
+    SetPlayChainMode { play_chain_id: ChainId, mode: PlayChainMode },
+    RemovePlayChain { play_chain_id: ChainId },
+    SetTableStakes { play_chain_id: ChainId, stakes: TableStakes },
     MintToken { chain_id: ChainId, amount: Amount },
+    SetTableRules { play_chain_id: ChainId, rules: RuleVariant },
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum BlackjackMessage {
     // * User Chain
-    FindPlayChainResult { chain_id: Option<ChainId> },
-    RequestTableSeatResult { seat_id: u8, success: bool },
+    LegacyFindPlayChainResult { chain_id: Option<ChainId> }, // baseline layout, read as FindPlayChainResult
+    LegacyRequestTableSeatResult { seat_id: u8, success: bool }, // baseline layout, read as RequestTableSeatResult
     // * Play Chain
     Subscribe,
     Unsubscribe,
-    RequestTableSeat { seat_id: u8, balance: Amount },
+    LegacyRequestTableSeat { seat_id: u8, balance: Amount }, // baseline layout, read as RequestTableSeat
     // * Public Chain
-    FindPlayChain,
+    LegacyFindPlayChain, // baseline layout, read as FindPlayChain
     AddPlayChain { chain_id: ChainId },
+    // * Schema
+    Versioned { envelope: Envelope }, // keep the variants above at their baseline index, add new variants below
+    // * User Chain
+    PublicChains { chains: Vec<ChainId> },
+    FindPlayChainResult { chain_id: Option<ChainId>, request_id: u64 },
+    RequestTableSeatResult { request_id: u64, result: TableSeatResult },
+    TableClosed,
+    TableSnapshot { game: BlackjackGame },
+    PrivateTableResult { accepted: bool, request_id: u64 },
+    WaitlistJoined { position: Option<u32> }, // None when refused or the place in line was lost
+    SeatAvailable { offer: SeatOffer },
+    RoundResult { settlement: RoundSettlement },
+    SeatReleased { seat_id: u8, forfeited: Amount }, // bet kept by the Play Chain when leaving mid-hand
+    TableModeChanged { mode: PlayChainMode },        // to seated and waiting players
+    PrivateTableReservation { reserved: bool },      // reply to a reservation, also sent with false when the table is released
+    // * Play Chain
+    RequestTableSeat { seat_id: u8, balance: Amount, request_id: u64 },
+    LeaveTable { seat_id: u8 },
+    SetTableStakes { stakes: TableStakes },
+    SetPlayChainMode { mode: PlayChainMode },
+    ClosePlayChain,
+    ResyncTable,
+    ChatMessage { text: String },
+    ChatEmote { emote: Emote },
+    ReservePrivateTable { table: PrivateTable },
+    ReleasePrivateTable,
+    EnterPrivateTable { invite_code: String, request_id: u64 },
+    JoinWaitlist { seat_id: Option<u8> },
+    LeaveWaitlist,
+    SetTableRules { rules: RuleVariant },
+    PlayChainAssigned { public_chain: ChainId },
+    PlaceTableBet { seat_id: u8, amount: Amount },
+    DealTable { seat_id: u8 }, // deals once every seat has bet, otherwise when the betting time runs out
+    TableAction { seat_id: u8, action: PlayerAction },
+    Spectate,
+    StopSpectating,
+    SeatReconciled { seat_id: u8, balance: Amount, round: u64 }, // escrowed balance once the round is settled, before each bet
+    // * Public Chain
+    SetPublicChainDraining { draining: bool },
+    FindPlayChain { filter: TableFilter, request_id: u64 },
+    PlayChainHeartbeat { heartbeat: PlayChainHeartbeat },
+    PlayChainListing { stakes: Option<TableStakes>, rules: RuleVariant },
+    PlayChainModeUpdate { mode: PlayChainMode },
+    RemovePlayChain,
+    // * Master Chain
+    RequestPublicChains,
+    PlayChainListed { chain_id: ChainId },
+}
+
+impl BlackjackMessage {
+    pub fn versioned(message: &BlackjackMessage) -> Self {
+        BlackjackMessage::Versioned {
+            envelope: Envelope::seal(message),
+        }
+    }
+
+    /// Unwrap a versioned message, None when it does not decode with this schema.
+    /// Bare messages come from the baseline bytecode, its requests carry no id and are read with request id 0.
+    pub fn into_current(self) -> Option<Self> {
+        let message = match self {
+            BlackjackMessage::Versioned { envelope } => envelope.open()?,
+            message => message,
+        };
+        Some(match message {
+            BlackjackMessage::LegacyFindPlayChainResult { chain_id } => BlackjackMessage::FindPlayChainResult { chain_id, request_id: 0 },
+            BlackjackMessage::LegacyRequestTableSeatResult { seat_id, success } => BlackjackMessage::RequestTableSeatResult {
+                request_id: 0,
+                result: TableSeatResult {
+                    seat_id,
+                    success,
+                    stakes: None,
+                },
+            },
+            BlackjackMessage::LegacyRequestTableSeat { seat_id, balance } => BlackjackMessage::RequestTableSeat {
+                seat_id,
+                balance,
+                request_id: 0,
+            },
+            BlackjackMessage::LegacyFindPlayChain => BlackjackMessage::FindPlayChain {
+                filter: TableFilter::default(),
+                request_id: 0,
+            },
+            message => message,
+        })
+    }
+
+    /// Reply in the baseline layout to a request from the baseline bytecode, which can't open envelopes.
+    pub fn baseline_reply(&self) -> Option<Self> {
+        match self {
+            BlackjackMessage::FindPlayChainResult { chain_id, request_id: 0 } => Some(BlackjackMessage::LegacyFindPlayChainResult { chain_id: *chain_id }),
+            BlackjackMessage::RequestTableSeatResult { request_id: 0, result } => Some(BlackjackMessage::LegacyRequestTableSeatResult {
+                seat_id: result.seat_id,
+                success: result.success,
+            }),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -59,10 +194,58 @@ pub struct BlackjackParameters {
     pub master_chain: ChainId,
     pub public_chains: Vec<ChainId>,
     pub bankroll: ApplicationId<BankrollAbi>,
+    pub single_player_stakes: Option<TableStakes>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum BlackjackEvent {
     // * Event Subscriber
-    GameState { game: BlackjackGame },
+    GameState { game: BlackjackGame }, // full snapshot, emitted periodically and on close
+    PlayerSeated { sequence: u64, player: Player },
+    PlayerLeft { sequence: u64, seat_id: u8 },
+    BetPlaced { sequence: u64, seat_id: u8, amount: Amount },
+    CardDealt { sequence: u64, seat_id: Option<u8>, card: u8 }, // dealer card when seat_id is None, 0 when face down
+    PlayerActed { sequence: u64, seat_id: u8, action: PlayerAction },
+    DealerRevealed { sequence: u64, hand: Vec<u8> },
+    RoundSettled { sequence: u64, results: Vec<SeatSettlement> },
+    ShoeShuffled { sequence: u64, count: u64 },
+    // * Schema
+    Versioned { envelope: Envelope }, // keep at this index, add new variants below it
+    SpectatorsChanged { sequence: u64, count: u32 },
+    TableChat { entry: ChatEntry }, // emitted on CHAT_STREAM_NAME
+}
+
+impl BlackjackEvent {
+    /// Position of the event in the table stream, None for events outside of it.
+    pub fn sequence(&self) -> Option<u64> {
+        match self {
+            BlackjackEvent::GameState { game } => Some(game.sequence),
+            BlackjackEvent::PlayerSeated { sequence, .. }
+            | BlackjackEvent::PlayerLeft { sequence, .. }
+            | BlackjackEvent::BetPlaced { sequence, .. }
+            | BlackjackEvent::CardDealt { sequence, .. }
+            | BlackjackEvent::PlayerActed { sequence, .. }
+            | BlackjackEvent::DealerRevealed { sequence, .. }
+            | BlackjackEvent::RoundSettled { sequence, .. }
+            | BlackjackEvent::ShoeShuffled { sequence, .. }
+            | BlackjackEvent::SpectatorsChanged { sequence, .. } => Some(*sequence),
+            BlackjackEvent::Versioned { .. } => None, // unknown until opened
+            BlackjackEvent::TableChat { .. } => None, // chat stream is not sequenced
+        }
+    }
+
+    pub fn versioned(event: &BlackjackEvent) -> Self {
+        BlackjackEvent::Versioned {
+            envelope: Envelope::seal(event),
+        }
+    }
+
+    /// Unwrap a versioned event, None when it does not decode with this schema.
+    /// Events stored before envelopes existed pass through unchanged, they were all `GameState`.
+    pub fn into_current(self) -> Option<Self> {
+        match self {
+            BlackjackEvent::Versioned { envelope } => envelope.open(),
+            event => Some(event),
+        }
+    }
 }
@@ -6,11 +6,12 @@ use std::sync::Arc;
 
 use self::state::BlackjackState;
 use abi::bet_chip_profile::Profile;
-use abi::blackjack::{GameData, UserStatus};
+use abi::blackjack::{BlackjackGame, GameData, PlayChainHealth, PrivateTable, PublicChainStatus, RoundSettlement, SeatOffer, UserStatus, WaitlistEntry};
+use abi::chat::ChatEntry;
 use abi::deck::Deck;
-use async_graphql::{EmptySubscription, Object, Schema};
-use blackjack::BlackjackOperation;
-use linera_sdk::linera_base_types::ChainId;
+use async_graphql::{EmptySubscription, Object, Request, Schema};
+use blackjack::{BlackjackOperation, BlackjackParameters};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ChainId};
 use linera_sdk::{graphql::GraphQLMutationRoot, linera_base_types::WithServiceAbi, views::View, Service, ServiceRuntime};
 
 pub struct BlackjackService {
@@ -25,7 +26,7 @@ impl WithServiceAbi for BlackjackService {
 }
 
 impl Service for BlackjackService {
-    type Parameters = ();
+    type Parameters = BlackjackParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = BlackjackState::load(runtime.root_view_storage_context()).await.expect("Failed to load state");
@@ -64,6 +65,33 @@ impl QueryRoot {
     async fn get_play_chains(&self) -> Vec<ChainId> {
         self.state.play_chain_status.indices().await.unwrap_or_default()
     }
+    /// Public chains this chain picks from, the deployment list until synced from the Master Chain.
+    async fn get_public_chains(&self) -> Vec<ChainId> {
+        match self.state.public_chains.get().clone() {
+            Some(public_chains) => public_chains,
+            None => self.runtime.application_parameters().public_chains,
+        }
+    }
+    async fn get_public_chain_status(&self, chain_id: ChainId) -> Option<PublicChainStatus> {
+        self.state.public_chain_registry.get(&chain_id).await.unwrap_or_default()
+    }
+    /// Heartbeat and staleness of every listed Play Chain, for ops dashboards.
+    async fn get_play_chain_health(&self) -> Vec<PlayChainHealth> {
+        let current_time = self.runtime.system_time();
+        let health_keys = self.state.play_chain_health.indices().await.unwrap_or_default();
+        let mut data = Vec::new();
+
+        for key in health_keys.into_iter() {
+            if let Some(mut health) = self.state.play_chain_health.get(&key).await.unwrap_or_default() {
+                health.stale = health.is_stale(current_time);
+                data.push(health);
+            }
+        }
+        data
+    }
+    async fn get_play_chain_occupancy(&self, chain_id: ChainId) -> Option<u8> {
+        self.state.play_chain_status.get(&chain_id).await.unwrap_or_default()
+    }
     async fn single_player_data(&self) -> GameData {
         GameData {
             user_status: self.state.user_status.get().clone(),
@@ -78,10 +106,66 @@ impl QueryRoot {
             game: self.state.event_game_state.get().data_for_event(),
         }
     }
+    async fn get_spectated_play_chain(&self) -> Option<ChainId> {
+        if self.state.user_status.get().ne(&UserStatus::Spectating) {
+            return None;
+        }
+        *self.state.user_play_chain.get()
+    }
+    /// Latest state of the table the user is watching, None unless Spectating.
+    async fn spectated_table_data(&self) -> Option<BlackjackGame> {
+        if self.state.user_status.get().ne(&UserStatus::Spectating) {
+            return None;
+        }
+        Some(self.state.event_game_state.get().clone())
+    }
+    /// Spectators of the table the user sits at or watches, as last announced by the table.
+    async fn table_spectator_count(&self) -> u32 {
+        *self.state.table_spectator_count.get()
+    }
+    async fn get_spectators(&self) -> Vec<ChainId> {
+        self.state.play_chain_spectators.indices().await.unwrap_or_default()
+    }
+    /// Reservation of this Play Chain, None for public tables.
+    async fn get_private_table(&self) -> Option<PrivateTable> {
+        self.state.private_table.get().clone()
+    }
+    /// Play Chain waiting list, first in line first.
+    async fn get_seat_waitlist(&self) -> Vec<WaitlistEntry> {
+        self.state.seat_waitlist.get().clone()
+    }
+    /// Seat offered to this user chain while on a waiting list.
+    async fn get_seat_offer(&self) -> Option<SeatOffer> {
+        self.state.seat_offer.get().clone()
+    }
+    /// Last multi player round settled at the current table.
+    async fn get_last_settlement(&self) -> Option<RoundSettlement> {
+        self.state.last_settlement.get().clone()
+    }
+    /// Recent table chat, oldest first.
+    async fn get_chat_history(&self) -> Vec<ChatEntry> {
+        self.state.chat_history.get().clone()
+    }
+    async fn get_muted_chats(&self) -> Vec<ChainId> {
+        self.state.chat_muted.indices().await.unwrap_or_default()
+    }
     async fn get_profile(&self) -> Profile {
         self.state.profile.get().clone()
     }
     async fn get_user_status(&self) -> UserStatus {
         self.state.user_status.get().clone()
     }
+    /// Current Bankroll balance, read through the Bankroll service without creating a block.
+    async fn live_balance(&self) -> async_graphql::Result<Amount> {
+        let owner: AccountOwner = self.runtime.application_id().into();
+        let bankroll_app_id = self.runtime.application_parameters().bankroll;
+        let request = Request::new(format!("query {{ getAccountBalance(owner: \"{}\") }}", owner));
+        let response = self.runtime.query_application(bankroll_app_id, &request);
+        if let Some(error) = response.errors.first() {
+            return Err(format!("Bankroll balance query failed: {}", error.message).into());
+        }
+        let data = response.data.into_json()?;
+        let balance = data["getAccountBalance"].as_str().ok_or("Bankroll response has no balance")?;
+        Ok(balance.parse::<Amount>()?)
+    }
 }
@@ -1,8 +1,13 @@
-use abi::bet_chip_profile::Profile;
-use abi::blackjack::{BlackjackGame, UserStatus};
+use abi::bet_chip_profile::{Profile, TableStakes};
+use abi::blackjack::{
+    BlackjackGame, PendingRequest, PlayChainHealth, PlayChainMode, PrivateTable, PublicChainStatus, RoundSettlement, RuleVariant, SeatOffer, TableFilter,
+    TableListing, UserStatus, WaitlistEntry,
+};
+use abi::chat::ChatEntry;
 use abi::deck::Deck;
 use abi::player_dealer::Player;
-use linera_sdk::linera_base_types::{Amount, ChainId};
+use blackjack::BlackjackMessage;
+use linera_sdk::linera_base_types::{Amount, ChainId, Timestamp};
 use linera_sdk::views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext};
 
 #[derive(RootView, async_graphql::SimpleObject)]
@@ -11,19 +16,56 @@ pub struct BlackjackState {
     pub instantiate_value: RegisterView<u64>,
     // All Chain
     pub blackjack_token_pool: RegisterView<Amount>,
+    pub chat_history: RegisterView<Vec<ChatEntry>>, // recent table chat, bounded by CHAT_HISTORY_SIZE
+    // Master Chain
+    pub public_chain_registry: MapView<ChainId, PublicChainStatus>,
+    pub public_chain_registry_seeded: RegisterView<bool>, // registry starts from BlackjackParameters::public_chains
     // Public Chain
+    pub public_chain_draining: RegisterView<bool>,
     pub play_chain_set: MapView<u8, Vec<ChainId>>,
     pub play_chain_status: MapView<ChainId, u8>,
+    pub play_chain_listing: MapView<ChainId, TableListing>,
+    pub play_chain_health: MapView<ChainId, PlayChainHealth>,
+    pub play_chain_assignments: RegisterView<u64>, // sequence for least-recently-assigned matchmaking
     // User Chain
     pub profile: RegisterView<Profile>,
     pub player_seat_map: MapView<u8, Player>, // whenever this updated, the one in BlackjackGame should be updated too
     pub user_status: RegisterView<UserStatus>,
-    pub user_play_chain: RegisterView<Option<ChainId>>,
+    pub pending_request: RegisterView<Option<PendingRequest>>, // FindPlayChain or RequestTableSeat awaiting a reply
+    #[graphql(skip)]
+    pub pending_request_message: RegisterView<Option<(ChainId, BlackjackMessage)>>, // resent as is when the request expires
+    pub request_sequence: RegisterView<u64>,
+    pub user_play_chain: RegisterView<Option<ChainId>>, // also the spectated table while Spectating
     pub find_play_chain_retry: RegisterView<u8>,
+    pub find_play_chain_filter: RegisterView<TableFilter>,
     pub event_game_state: RegisterView<BlackjackGame>,
+    pub table_spectator_count: RegisterView<u32>, // last count announced by the current table
+    pub table_resync_pending: RegisterView<bool>, // ResyncTable sent, waiting for the TableSnapshot
+    pub chat_muted: MapView<ChainId, Timestamp>,  // chat senders muted since
+    pub on_waitlist: RegisterView<bool>,
+    pub seat_offer: RegisterView<Option<SeatOffer>>,            // freed seat to claim with RequestTableSeat
+    pub last_settlement: RegisterView<Option<RoundSettlement>>, // last round settled at the current table
+    pub pending_leave: RegisterView<Option<ChainId>>,           // table left mid-hand, escrow held until SeatReleased
+    pub table_mode: RegisterView<Option<PlayChainMode>>,        // last mode announced by the current table
     pub single_player_game: RegisterView<BlackjackGame>,
     pub token_pool_address: RegisterView<Option<ChainId>>,
+    pub public_chains: RegisterView<Option<Vec<ChainId>>>, // live public chains, None until synced from the Master Chain
+    pub table_stakes: RegisterView<Option<TableStakes>>,
+    pub private_tables: RegisterView<Vec<ChainId>>, // reserved or awaiting a reply, bounded by MAX_PRIVATE_TABLES_PER_OWNER
     // Play Chain
     pub deck_card: RegisterView<Deck>,
     pub game: RegisterView<BlackjackGame>,
+    pub play_chain_stakes: RegisterView<Option<TableStakes>>,
+    pub play_chain_rules: RegisterView<RuleVariant>,
+    pub play_chain_public_chain: RegisterView<Option<ChainId>>, // public chain listing this play chain
+    pub play_chain_mode: RegisterView<PlayChainMode>,
+    pub play_chain_spectators: MapView<ChainId, Timestamp>, // spectating since
+    pub chat_last_sent: MapView<ChainId, Timestamp>,        // per sender, for the chat rate limit
+    pub private_table: RegisterView<Option<PrivateTable>>,
+    pub private_table_guests: MapView<ChainId, Timestamp>, // chains that entered with a valid invite code
+    pub private_table_active_at: RegisterView<Timestamp>,  // released once idle for PRIVATE_TABLE_IDLE_DURATION_IN_MICROS
+    pub seat_waitlist: RegisterView<Vec<WaitlistEntry>>,   // first come, first served
+    pub seat_offers: MapView<u8, SeatOffer>,               // freed seats held for waitlisted chains
+    pub seat_settled_rounds: MapView<u8, u64>,             // last round settled with a result for the seat
+    pub seat_reconciled_rounds: MapView<u8, u64>,          // seats take bets once reconciled after their last settled round
 }
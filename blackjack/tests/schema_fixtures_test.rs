@@ -0,0 +1,94 @@
+use abi::blackjack::{BlackjackGame, BlackjackStatus, TableFilter, TableSeatResult};
+use abi::schema::SCHEMA_VERSION;
+use blackjack::{BlackjackEvent, BlackjackMessage};
+use linera_sdk::linera_base_types::Amount;
+
+// BCS payloads as sent by the released bytecode from before request ids and envelopes, do not regenerate
+const LEGACY_FIND_PLAY_CHAIN_RESULT: &[u8] = &[0, 0];
+const LEGACY_REQUEST_TABLE_SEAT_RESULT: &[u8] = &[1, 2, 1];
+const LEGACY_SUBSCRIBE: &[u8] = &[2];
+const LEGACY_UNSUBSCRIBE: &[u8] = &[3];
+const LEGACY_REQUEST_TABLE_SEAT: &[u8] = &[4, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const LEGACY_FIND_PLAY_CHAIN: &[u8] = &[5];
+// GameState of an empty table at sequence 7, waiting for bets
+const LEGACY_GAME_STATE: &[u8] = &[
+    0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+];
+// LeaveTable { seat_id: 2 } inside a schema version 1 envelope
+const VERSIONED_LEAVE_TABLE: &[u8] = &[7, 1, 0, 2, 21, 2];
+// Schema version 2 envelope holding a variant unknown to this bytecode
+const FUTURE_MESSAGE: &[u8] = &[7, 2, 0, 1, 100];
+const FUTURE_EVENT: &[u8] = &[9, 2, 0, 1, 40];
+
+fn decode_message(bytes: &[u8]) -> Option<BlackjackMessage> {
+    bcs::from_bytes::<BlackjackMessage>(bytes).expect("fixture must decode").into_current()
+}
+
+#[test]
+fn test_legacy_messages_decode() {
+    assert_eq!(decode_message(LEGACY_SUBSCRIBE), Some(BlackjackMessage::Subscribe));
+    assert_eq!(decode_message(LEGACY_UNSUBSCRIBE), Some(BlackjackMessage::Unsubscribe));
+    assert_eq!(
+        decode_message(LEGACY_REQUEST_TABLE_SEAT),
+        Some(BlackjackMessage::RequestTableSeat {
+            seat_id: 1,
+            balance: Amount::from_attos(5),
+            request_id: 0,
+        })
+    );
+    assert_eq!(
+        decode_message(LEGACY_FIND_PLAY_CHAIN),
+        Some(BlackjackMessage::FindPlayChain {
+            filter: TableFilter::default(),
+            request_id: 0,
+        })
+    );
+    assert_eq!(
+        decode_message(LEGACY_FIND_PLAY_CHAIN_RESULT),
+        Some(BlackjackMessage::FindPlayChainResult { chain_id: None, request_id: 0 })
+    );
+}
+
+#[test]
+fn test_legacy_requests_get_legacy_replies() {
+    let result = TableSeatResult {
+        seat_id: 2,
+        success: true,
+        stakes: None,
+    };
+    let reply = BlackjackMessage::RequestTableSeatResult { request_id: 0, result };
+    assert_eq!(decode_message(LEGACY_REQUEST_TABLE_SEAT_RESULT), Some(reply.clone()));
+    assert_eq!(bcs::to_bytes(&reply.baseline_reply().unwrap()).unwrap(), LEGACY_REQUEST_TABLE_SEAT_RESULT);
+
+    let reply = BlackjackMessage::FindPlayChainResult { chain_id: None, request_id: 0 };
+    assert_eq!(bcs::to_bytes(&reply.baseline_reply().unwrap()).unwrap(), LEGACY_FIND_PLAY_CHAIN_RESULT);
+
+    // Replies to requests with an id are sent versioned
+    let reply = BlackjackMessage::FindPlayChainResult { chain_id: None, request_id: 3 };
+    assert_eq!(reply.baseline_reply(), None);
+}
+
+#[test]
+fn test_legacy_game_state_decodes() {
+    let mut game = BlackjackGame::default();
+    game.sequence = 7;
+    game.status = BlackjackStatus::WaitingForBets;
+    let event = bcs::from_bytes::<BlackjackEvent>(LEGACY_GAME_STATE).expect("fixture must decode");
+    assert_eq!(event.into_current(), Some(BlackjackEvent::GameState { game }));
+}
+
+#[test]
+fn test_versioned_message_decodes() {
+    assert_eq!(decode_message(VERSIONED_LEAVE_TABLE), Some(BlackjackMessage::LeaveTable { seat_id: 2 }));
+    let sealed = BlackjackMessage::versioned(&BlackjackMessage::LeaveTable { seat_id: 2 });
+    let mut expected = VERSIONED_LEAVE_TABLE.to_vec();
+    expected[1..3].copy_from_slice(&SCHEMA_VERSION.to_le_bytes());
+    assert_eq!(bcs::to_bytes(&sealed).unwrap(), expected);
+}
+
+#[test]
+fn test_future_payloads_are_skipped() {
+    assert_eq!(decode_message(FUTURE_MESSAGE), None);
+    let event = bcs::from_bytes::<BlackjackEvent>(FUTURE_EVENT).expect("fixture must decode");
+    assert_eq!(event.into_current(), None);
+}